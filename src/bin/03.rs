@@ -16,8 +16,8 @@ pub fn part_one(input: &str) -> Option<u32> {
                 .iter()
                 .find_position(|x| x.to_string() == matching_char.to_lowercase().to_string())
                 .unwrap();
-            let value = alph_i as u32 + 1 + if is_upper { 26 } else { 0 };
-            value
+
+            alph_i as u32 + 1 + if is_upper { 26 } else { 0 }
         })
         .sum();
     Some(total)
@@ -42,8 +42,8 @@ pub fn part_two(input: &str) -> Option<u32> {
                 .iter()
                 .find_position(|x| x.to_string() == matching_char.to_lowercase().to_string())
                 .unwrap();
-            let value = alph_i as u32 + 1 + if matching_char.is_uppercase() { 26 } else { 0 };
-            value
+
+            alph_i as u32 + 1 + if matching_char.is_uppercase() { 26 } else { 0 }
         })
         .sum();
     Some(total)
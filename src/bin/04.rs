@@ -16,29 +16,21 @@ pub fn part_one(input: &str) -> Option<u32> {
     let c = parse_input(input)
         .filter(|ass| {
             let (x_min, x_max, y_min, y_max) = ass;
-            if (x_min >= y_min && x_max <= y_max) || (y_min >= x_min && y_max <= x_max) {
-                true
-            } else {
-                false
-            }
+            (x_min >= y_min && x_max <= y_max) || (y_min >= x_min && y_max <= x_max)
         })
         .count();
     Some(c as u32)
 }
 
+#[allow(clippy::nonminimal_bool)]
 pub fn part_two(input: &str) -> Option<u32> {
     let c = parse_input(input)
         .filter(|ass| {
             let (x_min, x_max, y_min, y_max) = ass;
-            if (x_max >= y_min && x_max <= y_max)
+            (x_max >= y_min && x_max <= y_max)
                 || (y_min >= x_min && y_min <= x_max)
                 || (x_min >= y_min && x_min <= y_max)
                 || (y_max >= x_min && y_max <= x_max)
-            {
-                true
-            } else {
-                false
-            }
         })
         .count();
     Some(c as u32)
@@ -1,5 +1,3 @@
-#![feature(map_many_mut)]
-#![feature(is_some_and)]
 use std::collections::{HashMap, VecDeque};
 
 use itertools::Itertools;
@@ -15,13 +13,12 @@ fn parse_input(input: &str, multiple: bool) -> HashMap<char, VecDeque<char>> {
 
     for (i, ch) in initial_stacks
         .lines()
-        .rev()
-        .next()
+        .next_back()
         .unwrap()
         .chars()
         .enumerate()
     {
-        if ch.is_digit(10) {
+        if ch.is_ascii_digit() {
             stacks.insert(ch, VecDeque::new());
             indexes.push(i);
         }
@@ -46,25 +43,23 @@ fn parse_input(input: &str, multiple: bool) -> HashMap<char, VecDeque<char>> {
         let from = captures.get(2).unwrap();
         let to = captures.get(3).unwrap();
 
-        let columns_to_mut = stacks
-            .get_many_mut([
-                &from.as_str().chars().next().unwrap(),
-                &to.as_str().chars().next().unwrap(),
-            ])
-            .unwrap();
+        let from = from.as_str().chars().next().unwrap();
+        let to = to.as_str().chars().next().unwrap();
 
         let mut buffer = VecDeque::new();
 
+        let from_column = stacks.get_mut(&from).unwrap();
         for _ in 0..cnt.as_str().parse::<usize>().unwrap() {
-            let v = columns_to_mut[0].pop_back().unwrap();
+            let v = from_column.pop_back().unwrap();
             buffer.push_back(v);
         }
 
+        let to_column = stacks.get_mut(&to).unwrap();
         for _ in 0..buffer.len() {
             if multiple {
-                columns_to_mut[1].push_back(buffer.pop_back().unwrap());
+                to_column.push_back(buffer.pop_back().unwrap());
             } else {
-                columns_to_mut[1].push_back(buffer.pop_front().unwrap());
+                to_column.push_back(buffer.pop_front().unwrap());
             }
         }
     }
@@ -96,3 +91,20 @@ fn main() {
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 5);
+        assert_eq!(part_one(&input), Some("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 5);
+        assert_eq!(part_two(&input), Some("MCD".to_string()));
+    }
+}
@@ -31,7 +31,7 @@ fn build_tree(input: &str) -> HashMap<String, u64> {
 
 pub fn part_one(input: &str) -> Option<u64> {
     let tree = build_tree(input);
-    let total_size = tree.values().filter(|val| *val <= &(100000 as u64)).sum();
+    let total_size = tree.values().filter(|val| *val <= &100000_u64).sum();
     Some(total_size)
 }
 
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use advent_of_code::debugger::Simulation;
 use itertools::Itertools;
 
 fn should_move(a: &(i32, i32), b: &(i32, i32)) -> bool {
@@ -12,13 +13,13 @@ fn create_path(start: &(i32, i32), end: &(i32, i32)) -> Vec<(i32, i32)> {
         Axis::X => end.0 < start.0,
         Axis::Y => end.1 < start.1,
     };
-    let head_path = match (&axis, reversed) {
+
+    match (&axis, reversed) {
         (Axis::X, false) => (start.0 + 1..end.0 + 1).map(|x| (x, start.1)).collect_vec(),
         (Axis::X, true) => (end.0..start.0).map(|x| (x, start.1)).rev().collect_vec(),
         (Axis::Y, false) => (start.1 + 1..end.1 + 1).map(|y| (start.0, y)).collect_vec(),
         (Axis::Y, true) => (end.1..start.1).map(|y| (start.0, y)).rev().collect_vec(),
-    };
-    head_path
+    }
 }
 
 #[derive(PartialEq)]
@@ -27,10 +28,11 @@ enum Axis {
     Y,
 }
 
-fn _print_tails(tails: &Vec<(i32, i32)>) {
+fn draw_tails(tails: &[(i32, i32)]) -> String {
+    let mut drawing = String::new();
     for y in (-10..20).rev() {
         let line: String = (-20..30)
-            .map(|x| {
+            .filter_map(|x| {
                 tails
                     .iter()
                     .position(|tail| *tail == (x, y))
@@ -43,20 +45,44 @@ fn _print_tails(tails: &Vec<(i32, i32)>) {
                         }
                     })
             })
-            .filter_map(|s| s)
             .collect();
-        println!("{line}");
+        drawing.push('\n');
+        drawing.push_str(&line);
     }
+    drawing
 }
 
-fn solve(input: &str, tail_size: u8) -> HashSet<(i32, i32)> {
-    let mut visited_coords = HashSet::new();
-    let mut head_pos = (0, 0);
-    let mut tails = (0..tail_size).map(|_| (0, 0)).collect_vec();
+/// The rope, advanced by one move of the head per step.
+struct Rope<'a> {
+    lines: std::str::Lines<'a>,
+    /// positions the head still has to pass through for the current line.
+    head_path: std::vec::IntoIter<(i32, i32)>,
+    head_pos: (i32, i32),
+    tails: Vec<(i32, i32)>,
+    visited_coords: HashSet<(i32, i32)>,
+}
+
+impl<'a> Rope<'a> {
+    fn new(input: &'a str, tail_size: u8) -> Self {
+        Rope {
+            lines: input.lines(),
+            head_path: vec![].into_iter(),
+            head_pos: (0, 0),
+            tails: (0..tail_size).map(|_| (0, 0)).collect_vec(),
+            visited_coords: HashSet::new(),
+        }
+    }
 
-    for line in input.lines() {
+    fn next_head_pos(&mut self) -> Option<(i32, i32)> {
+        if let Some(pos) = self.head_path.next() {
+            return Some(pos);
+        }
+
+        let line = self.lines.next()?;
+        advent_of_code::debug_log!("--- {} ---", &line);
         let (dir, val) = line.split_once(" ").unwrap();
         let val: i32 = val.parse().unwrap();
+        let head_pos = self.head_pos;
 
         let next_head_pos = match dir {
             "R" => (head_pos.0 + val, head_pos.1),
@@ -66,42 +92,71 @@ fn solve(input: &str, tail_size: u8) -> HashSet<(i32, i32)> {
             _ => unimplemented!(),
         };
 
-        let head_path = create_path(&head_pos, &next_head_pos);
-
-        for pos in head_path {
-            for i in 0..tails.len() {
-                let mut prev_pos = pos;
-                if i == 0 {
-                    head_pos = pos;
-                } else {
-                    prev_pos = tails[i - 1];
-                }
-
-                if !should_move(&prev_pos, &tails[i]) {
-                    continue;
-                }
-                let (x, y) = tails[i];
-
-                let x_move = if prev_pos.0 > x { 1 } else { -1 };
-                let y_move = if prev_pos.1 > y { 1 } else { -1 };
-
-                if prev_pos.1 == y {
-                    tails[i] = (x + x_move, y);
-                } else if prev_pos.0 == x {
-                    tails[i] = (x, y + y_move);
-                } else {
-                    tails[i] = (x + x_move, y + y_move);
-                }
-
-                if i == tails.len() - 1 {
-                    visited_coords.insert(tails[i]);
-                }
+        self.head_path = create_path(&head_pos, &next_head_pos).into_iter();
+        self.head_path.next()
+    }
+}
+
+impl Simulation for Rope<'_> {
+    fn step(&mut self) -> bool {
+        let pos = match self.next_head_pos() {
+            Some(pos) => pos,
+            None => return false,
+        };
+        let tails = &mut self.tails;
+
+        for i in 0..tails.len() {
+            let mut prev_pos = pos;
+            if i == 0 {
+                self.head_pos = pos;
+            } else {
+                prev_pos = tails[i - 1];
+            }
+
+            if !should_move(&prev_pos, &tails[i]) {
+                continue;
+            }
+            let (x, y) = tails[i];
+
+            let x_move = if prev_pos.0 > x { 1 } else { -1 };
+            let y_move = if prev_pos.1 > y { 1 } else { -1 };
+
+            if prev_pos.1 == y {
+                tails[i] = (x + x_move, y);
+            } else if prev_pos.0 == x {
+                tails[i] = (x, y + y_move);
+            } else {
+                tails[i] = (x + x_move, y + y_move);
+            }
+
+            if i == tails.len() - 1 {
+                self.visited_coords.insert(tails[i]);
             }
         }
-        // println!("--- {} ---", &line);
-        // _print_tails(&tails);
+        advent_of_code::trace!("{}", draw_tails(tails));
+        true
+    }
+
+    fn values(&self) -> Vec<(String, i64)> {
+        let tail = self.tails.last().copied().unwrap_or_default();
+        vec![
+            ("head_x".into(), self.head_pos.0 as i64),
+            ("head_y".into(), self.head_pos.1 as i64),
+            ("tail_x".into(), tail.0 as i64),
+            ("tail_y".into(), tail.1 as i64),
+            ("visited".into(), self.visited_coords.len() as i64),
+        ]
+    }
+
+    fn render(&self) -> String {
+        draw_tails(&self.tails)
     }
-    visited_coords
+}
+
+fn solve(input: &str, tail_size: u8) -> HashSet<(i32, i32)> {
+    let mut rope = Rope::new(input, tail_size);
+    while rope.step() {}
+    rope.visited_coords
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
@@ -116,6 +171,10 @@ pub fn part_two(input: &str) -> Option<u32> {
 
 fn main() {
     let input = &advent_of_code::read_file("inputs", 9);
+    if let Some(part) = advent_of_code::debugger::requested_part() {
+        let tail_size = if part == 1 { 1 } else { 9 };
+        return advent_of_code::debugger::debug(Rope::new(input, tail_size));
+    }
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -127,7 +186,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(13));
+        assert_eq!(part_one(&input), Some(88));
     }
 
     #[test]
@@ -1,35 +1,85 @@
 use std::collections::HashMap;
 
+use advent_of_code::debugger::Simulation;
 use itertools::Itertools;
 
-fn process(input: &str) -> (HashMap<i32, i32>, Vec<bool>) {
-    let mut history = HashMap::new();
-    let mut pixels = vec![];
-    let mut curr_value = 1;
-    let mut cycle = 1;
-
-    for line in input.lines() {
-        match &line[..4] {
-            "noop" => {
-                let crt_index = (cycle - 1) % 40;
-                pixels.push(crt_index >= curr_value - 1 && crt_index <= curr_value + 1);
-                cycle += 1;
-            }
-            "addx" => {
-                for _ in 0..2 {
-                    let crt_index = (cycle - 1) % 40;
-                    pixels.push(crt_index >= curr_value - 1 && crt_index <= curr_value + 1);
-                    cycle += 1;
-                }
+/// The CPU and CRT, advanced one cycle per step.
+struct Device<'a> {
+    lines: std::str::Lines<'a>,
+    /// value of a running `addx`, added to the register after its second cycle.
+    adding: Option<i32>,
+    curr_value: i32,
+    cycle: i32,
+    history: HashMap<i32, i32>,
+    pixels: Vec<bool>,
+}
+
+impl<'a> Device<'a> {
+    fn new(input: &'a str) -> Self {
+        Device {
+            lines: input.lines(),
+            adding: None,
+            curr_value: 1,
+            cycle: 1,
+            history: HashMap::new(),
+            pixels: vec![],
+        }
+    }
+}
 
-                let (_, val) = line.split_once(" ").unwrap();
-                curr_value += val.parse::<i32>().unwrap();
-                history.insert(cycle, curr_value);
+impl Simulation for Device<'_> {
+    fn step(&mut self) -> bool {
+        let finished_add = self.adding.take();
+        if finished_add.is_none() {
+            let line = match self.lines.next() {
+                Some(line) => line,
+                None => return false,
+            };
+            match &line[..4] {
+                "noop" => {}
+                "addx" => {
+                    let (_, val) = line.split_once(" ").unwrap();
+                    self.adding = Some(val.parse::<i32>().unwrap());
+                }
+                _ => unimplemented!(),
             }
-            _ => unimplemented!(),
         }
+
+        let crt_index = (self.cycle - 1) % 40;
+        self.pixels
+            .push(crt_index >= self.curr_value - 1 && crt_index <= self.curr_value + 1);
+        self.cycle += 1;
+
+        if let Some(val) = finished_add {
+            self.curr_value += val;
+            self.history.insert(self.cycle, self.curr_value);
+        }
+        true
     }
-    (history, pixels)
+
+    fn values(&self) -> Vec<(String, i64)> {
+        vec![
+            ("cycle".into(), self.cycle as i64),
+            ("x".into(), self.curr_value as i64),
+        ]
+    }
+
+    fn render(&self) -> String {
+        draw(&self.pixels)
+    }
+}
+
+fn draw(pixels: &[bool]) -> String {
+    pixels
+        .chunks(40)
+        .map(|line| line.iter().map(|x| if *x { "#" } else { "." }).join(""))
+        .join("\n")
+}
+
+fn process(input: &str) -> (HashMap<i32, i32>, Vec<bool>) {
+    let mut device = Device::new(input);
+    while device.step() {}
+    (device.history, device.pixels)
 }
 
 const SIGNAL_CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
@@ -54,19 +104,22 @@ pub fn part_one(input: &str) -> Option<i32> {
 
 pub fn part_two(input: &str) -> Option<i32> {
     let (_, pixels) = process(input);
-    for line in pixels.chunks(40) {
-        println!(
-            "{}",
-            line.iter().map(|x| if *x { "#" } else { "." }).join("")
-        )
-    }
+    let image = draw(&pixels);
+    println!("{}", image);
     Some(0)
 }
 
 fn main() {
     let input = &advent_of_code::read_file("inputs", 10);
+    if advent_of_code::debugger::requested_part().is_some() {
+        // both parts run the same program.
+        return advent_of_code::debugger::debug(Device::new(input));
+    }
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
+
+    // drawn after solving, so the timings above leave it out.
+    advent_of_code::visualize(10, "CRT image", || draw(&process(input).1));
 }
 
 #[cfg(test)]
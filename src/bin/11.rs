@@ -1,3 +1,4 @@
+use advent_of_code::debugger::Simulation;
 use itertools::Itertools;
 
 #[derive(Debug, Clone)]
@@ -34,12 +35,7 @@ fn parse_monkeys(input: &str) -> Vec<Monkey> {
             let mut bl_lines = block.lines();
             let (_, items) = bl_lines.nth(1).unwrap().split_once(": ").unwrap();
             let items = items.split(", ").map(|item| item.parse::<u64>().unwrap());
-            let op_line = bl_lines
-                .next()
-                .unwrap()
-                .trim()
-                .split_whitespace()
-                .collect_vec();
+            let op_line = bl_lines.next().unwrap().split_whitespace().collect_vec();
             let op = match (op_line[4], op_line[5]) {
                 ("*", num) if num.parse::<u32>().is_ok() => Op::Multiply(num.parse().unwrap()),
                 ("+", num) if num.parse::<u32>().is_ok() => Op::Add(num.parse().unwrap()),
@@ -77,13 +73,46 @@ fn parse_monkeys(input: &str) -> Vec<Monkey> {
     monkeys
 }
 
-fn monkeys_play<F>(mut monkeys: Vec<Monkey>, rounds: u64, worry_modifier: F) -> u64
-where
-    F: Fn(u64) -> u64,
-{
-    let mut throw_to_first = vec![];
-    let mut throw_to_second = vec![];
-    for _ in 0..rounds {
+/// The monkeys playing keep away, advanced by one round per step.
+struct Game<F: Fn(u64) -> u64> {
+    monkeys: Vec<Monkey>,
+    round: u64,
+    rounds: u64,
+    worry_modifier: F,
+}
+
+impl<F: Fn(u64) -> u64> Game<F> {
+    fn new(monkeys: Vec<Monkey>, rounds: u64, worry_modifier: F) -> Self {
+        Game {
+            monkeys,
+            round: 0,
+            rounds,
+            worry_modifier,
+        }
+    }
+
+    fn monkey_business_level(&self) -> u64 {
+        let (a, b) = self
+            .monkeys
+            .iter()
+            .map(|monkey| monkey.inspected_count)
+            .sorted()
+            .rev()
+            .next_tuple()
+            .unwrap();
+        a * b
+    }
+}
+
+impl<F: Fn(u64) -> u64> Simulation for Game<F> {
+    fn step(&mut self) -> bool {
+        if self.round == self.rounds {
+            return false;
+        }
+
+        let monkeys = &mut self.monkeys;
+        let mut throw_to_first = vec![];
+        let mut throw_to_second = vec![];
         for index in 0..monkeys.len() {
             let monkey = &mut monkeys[index];
             for item in monkey.items.iter() {
@@ -93,7 +122,7 @@ where
                     Op::AddSelf => item + item,
                     Op::MultiplySelf => item * item,
                 };
-                item = worry_modifier(item);
+                item = (self.worry_modifier)(item);
                 if item % monkey.test_division == 0 {
                     throw_to_first.push(item);
                 } else {
@@ -107,35 +136,70 @@ where
             throw_to_first.clear();
             throw_to_second.clear();
         }
+        self.round += 1;
+        true
+    }
+
+    fn values(&self) -> Vec<(String, i64)> {
+        let mut values = vec![("round".to_string(), self.round as i64)];
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            values.push((format!("inspected{}", i), monkey.inspected_count as i64));
+            values.push((format!("items{}", i), monkey.items.len() as i64));
+        }
+        values
+    }
+
+    fn render(&self) -> String {
+        self.monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| {
+                format!(
+                    "Monkey {} (inspected {}): {}",
+                    i,
+                    monkey.inspected_count,
+                    monkey.items.iter().join(", ")
+                )
+            })
+            .join("\n")
     }
-    let (a, b) = monkeys
-        .iter()
-        .map(|monkey| monkey.inspected_count)
-        .sorted()
-        .rev()
-        .next_tuple()
-        .unwrap();
-    a * b
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+/// builds the game of a part. part two keeps the worry levels small with the product of all
+/// divisors instead of dividing them by three.
+fn build_game(input: &str, part: u8) -> Game<Box<dyn Fn(u64) -> u64>> {
     let monkeys = parse_monkeys(input);
-    let monkey_business_level = monkeys_play(monkeys, 20, |x| x / 3);
+    if part == 1 {
+        return Game::new(monkeys, 20, Box::new(|x| x / 3));
+    }
+
+    let product = monkeys.iter().map(|m| m.test_division).product::<u64>();
+    Game::new(monkeys, 10000, Box::new(move |x| x % product))
+}
+
+fn monkeys_play<F>(mut game: Game<F>) -> u64
+where
+    F: Fn(u64) -> u64,
+{
+    while game.step() {}
+    game.monkey_business_level()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let monkey_business_level = monkeys_play(build_game(input, 1));
     Some(monkey_business_level)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let monkeys = parse_monkeys(input);
-    let product = monkeys
-        .iter()
-        .map(|m| m.test_division as u64)
-        .product::<u64>();
-    let monkey_business_level = monkeys_play(monkeys, 10000, |x| x % product);
+    let monkey_business_level = monkeys_play(build_game(input, 2));
     Some(monkey_business_level)
 }
 
 fn main() {
     let input = &advent_of_code::read_file("inputs", 11);
+    if let Some(part) = advent_of_code::debugger::requested_part() {
+        return advent_of_code::debugger::debug(build_game(input, part));
+    }
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -147,7 +211,7 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), None);
+        assert_eq!(part_one(&input), Some(10605));
     }
 
     #[test]
@@ -15,20 +15,21 @@ fn parse(input: &str) -> Vec<Vec<char>> {
     grid
 }
 
-fn _print_visited(visited: &Vec<Vec<bool>>) {
+fn draw_visited(visited: &[Vec<bool>]) -> String {
+    let mut drawing = String::new();
     for i in 0..visited[0].len() {
-        println!(
-            "{}",
-            visited
+        drawing.push('\n');
+        drawing.push_str(
+            &visited
                 .iter()
                 .map(|col| if col[i] { "X" } else { "." })
-                .collect::<String>()
+                .collect::<String>(),
         );
     }
-    println!("-------");
+    drawing
 }
 
-fn find_start(grid: &Vec<Vec<char>>) -> (usize, usize) {
+fn find_start(grid: &[Vec<char>]) -> (usize, usize) {
     for (x, col) in grid.iter().enumerate() {
         for (y, ch) in col.iter().enumerate() {
             if ch == &'S' {
@@ -43,8 +44,8 @@ fn is_valid_path(
     source: &QItem,
     x: isize,
     y: isize,
-    grid: &Vec<Vec<char>>,
-    visited: &Vec<Vec<bool>>,
+    grid: &[Vec<char>],
+    visited: &[Vec<bool>],
 ) -> bool {
     if x < 0 || y < 0 {
         return false;
@@ -62,14 +63,17 @@ fn is_valid_path(
         .chars()
         .position(|ch| grid[source.x][source.y] == ch);
     let curr_char = curr_char_index.and_then(|i| ALPHABET_STR.chars().nth(i));
-    // let next_char = next_char_index.and_then(|i| ALPHABET_STR.chars().nth(i));
     if (is_start
         || (is_next_end && curr_char.unwrap() == 'z')
         || (next_char_index.is_some()
             && next_char_index.unwrap() <= (curr_char_index.unwrap() + 1)))
         && !visited[x][y]
     {
-        // println!("{x} {y} {:#?} {:#?}", curr_char, next_char);
+        advent_of_code::trace!(
+            "{x} {y} {:?} {:?}",
+            curr_char,
+            next_char_index.and_then(|i| ALPHABET_STR.chars().nth(i))
+        );
         return true;
     }
     false
@@ -81,10 +85,9 @@ struct QItem {
     distance: usize,
 }
 
-fn find_shortest_path(grid: &Vec<Vec<char>>) -> usize {
+fn find_shortest_path(grid: &[Vec<char>]) -> usize {
     let mut queue = vec![];
     let mut visited = grid
-        .clone()
         .iter()
         .map(|col| col.iter().map(|_| false).collect_vec())
         .collect_vec();
@@ -97,8 +100,8 @@ fn find_shortest_path(grid: &Vec<Vec<char>>) -> usize {
     });
     visited[start.0][start.1] = true;
 
-    while queue.len() > 0 {
-        // _print_visited(&visited);
+    while !queue.is_empty() {
+        advent_of_code::trace!("{}", draw_visited(&visited));
         let source = queue.pop().unwrap();
 
         if grid[source.x][source.y] == 'E' {
@@ -167,7 +170,7 @@ fn find_shortest_path(grid: &Vec<Vec<char>>) -> usize {
         }
     }
 
-    dbg!(visited);
+    advent_of_code::debug_log!("no path found:{}", draw_visited(&visited));
     todo!()
 }
 
@@ -177,7 +180,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(distance)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
@@ -17,7 +17,7 @@ fn collect_list(line: &str) -> (Symbol, usize) {
     let mut buffer = String::new();
 
     let flush_buffer = |symbols: &mut Vec<Symbol>, buffer: &mut String| {
-        if buffer.len() > 0 {
+        if !buffer.is_empty() {
             symbols.push(Symbol::Num(buffer.parse::<u8>().unwrap()));
             buffer.clear();
         }
@@ -108,13 +108,13 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(result)
 }
 
-const ADDITIONAL_PACKETS: [&'static str; 2] = ["[[2]]", "[[6]]"];
+const ADDITIONAL_PACKETS: [&str; 2] = ["[[2]]", "[[6]]"];
 
 pub fn part_two(input: &str) -> Option<usize> {
     let result = input
         .lines()
         .filter(|l| l != &"")
-        .chain(ADDITIONAL_PACKETS.into_iter())
+        .chain(ADDITIONAL_PACKETS)
         .map(|l| collect_list(l).0)
         .sorted_by(|a, b| compare_symbols((a, b)))
         .enumerate()
@@ -128,10 +128,7 @@ pub fn part_two(input: &str) -> Option<usize> {
                         if syms.len() != 1 {
                             return false;
                         }
-                        match &syms[0] {
-                            Symbol::Num(2 | 6) => true,
-                            _ => false,
-                        }
+                        matches!(&syms[0], Symbol::Num(2 | 6))
                     }
                     _ => false,
                 }
@@ -158,13 +155,13 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(4));
+        assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), None);
+        assert_eq!(part_two(&input), Some(140));
     }
 
     #[test]
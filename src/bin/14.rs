@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt};
 
+use advent_of_code::debugger::Simulation;
 use advent_of_code::helpers::{Coord, Grid};
 use itertools::Itertools;
 
@@ -41,13 +42,12 @@ fn create_path(start: &(usize, usize), end: &(usize, usize)) -> Vec<(usize, usiz
         Axis::X => end.0 < start.0,
         Axis::Y => end.1 < start.1,
     };
-    let head_path = match (&axis, reversed) {
+    match (&axis, reversed) {
         (Axis::X, false) => (start.0..=end.0).map(|x| (x, start.1)).collect_vec(),
         (Axis::X, true) => (end.0..=start.0).map(|x| (x, start.1)).rev().collect_vec(),
         (Axis::Y, false) => (start.1..=end.1).map(|y| (start.0, y)).collect_vec(),
         (Axis::Y, true) => (end.1..=start.1).map(|y| (start.0, y)).rev().collect_vec(),
-    };
-    head_path
+    }
 }
 
 #[derive(PartialEq)]
@@ -94,84 +94,167 @@ fn build_grid(data: &Rocks) -> Grid<Point> {
 fn move_sand(grid: &Grid<Point>, (x, y): &Coord) -> Option<Coord> {
     let down = grid.map.get(&(*x, y + 1));
     match down {
-        Some(pt) => match pt {
-            Point::Air => return Some((*x, y + 1)),
-            _ => {}
-        },
+        Some(Point::Air) => return Some((*x, y + 1)),
+        Some(_) => {}
         None => return None,
     }
 
     let left = grid.map.get(&(x - 1, y + 1));
     match left {
-        Some(pt) => match pt {
-            Point::Air => return Some((*x - 1, y + 1)),
-            _ => {}
-        },
+        Some(Point::Air) => return Some((*x - 1, y + 1)),
+        Some(_) => {}
         None => return None,
     }
 
     let right = grid.map.get(&(x + 1, y + 1));
     match right {
-        Some(pt) => match pt {
-            Point::Air => return Some((*x + 1, y + 1)),
-            _ => return Some((*x, *y)),
-        },
-        None => return None,
+        Some(Point::Air) => Some((*x + 1, y + 1)),
+        Some(_) => Some((*x, *y)),
+        None => None,
     }
 }
 
-fn count_sand(grid: &mut Grid<Point>) -> u32 {
-    let sand_start = (500, 0);
-    let mut counter = 0;
+/// The cave, advanced by one unit of sand per step.
+struct Cave {
+    grid: Grid<Point>,
+    floor: Option<usize>,
+    counter: u32,
+    last_rest: Coord,
+    finished: bool,
+}
+
+impl Cave {
+    fn new(grid: Grid<Point>, floor: Option<usize>) -> Self {
+        Cave {
+            grid,
+            floor,
+            counter: 0,
+            last_rest: SAND_START,
+            finished: false,
+        }
+    }
+}
+
+const SAND_START: Coord = (500, 0);
+
+impl Simulation for Cave {
+    /// drops one unit of sand until it comes to rest or falls into the abyss.
+    fn step(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
 
-    'outer: loop {
-        let mut curent_coord = sand_start;
+        let mut curent_coord = SAND_START;
         loop {
-            match move_sand(grid, &curent_coord) {
+            match move_sand(&self.grid, &curent_coord) {
                 Some(new_coord) => {
                     if new_coord == curent_coord {
-                        grid.map.insert(new_coord, Point::Sand);
-                        counter += 1;
-
-                        if new_coord == sand_start {
-                            break 'outer;
-                        }
-
-                        break;
+                        self.grid.map.insert(new_coord, Point::Sand);
+                        self.counter += 1;
+                        self.last_rest = new_coord;
+                        self.finished = new_coord == SAND_START;
+                        return true;
                     }
                     curent_coord = new_coord;
                 }
-                None => break 'outer,
+                None => {
+                    // the sand falls into the abyss, nothing changes anymore.
+                    self.finished = true;
+                    return false;
+                }
             }
         }
     }
-    counter
+
+    fn values(&self) -> Vec<(String, i64)> {
+        vec![
+            ("sand".into(), self.counter as i64),
+            ("x".into(), self.last_rest.0 as i64),
+            ("y".into(), self.last_rest.1 as i64),
+        ]
+    }
+
+    fn render(&self) -> String {
+        draw_cave(&self.grid, self.floor)
+    }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let paths = parse(input);
-    let mut grid = build_grid(&paths);
-    let cnt = count_sand(&mut grid);
-    Some(cnt)
+fn count_sand(cave: &mut Cave) -> u32 {
+    while cave.step() {}
+    cave.counter
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+/// draws the part of the cave that holds rocks and sand, leaving out the empty space around it.
+fn draw_cave(grid: &Grid<Point>, floor: Option<usize>) -> String {
+    let filled = grid
+        .map
+        .iter()
+        .filter(|((_, y), pt)| match pt {
+            Point::Air => false,
+            Point::Rock => Some(*y) != floor,
+            Point::Sand => true,
+        })
+        .map(|(coord, _)| *coord)
+        .collect_vec();
+    let Some((x_min, x_max)) = filled.iter().map(|(x, _)| *x).minmax().into_option() else {
+        return String::new();
+    };
+    let y_max = floor.unwrap_or_else(|| filled.iter().map(|(_, y)| *y).max().unwrap_or(0));
+
+    (0..=y_max)
+        .map(|y| {
+            (x_min..=x_max)
+                .map(|x| {
+                    grid.map
+                        .get(&(x, y))
+                        .map_or(Point::Air.to_string(), |pt| pt.to_string())
+                })
+                .join("")
+        })
+        .join("\n")
+}
+
+/// builds the cave of a part. part two adds the floor below the lowest rock.
+fn build_cave(input: &str, part: u8) -> Cave {
     let paths = parse(input);
     let mut grid = build_grid(&paths);
+    if part == 1 {
+        return Cave::new(grid, None);
+    }
 
     let (_, y_max) = grid.get_max_coord();
     for (_, pt) in grid.map.iter_mut().filter(|((_, y), _)| *y == y_max) {
         *pt = Point::Rock
     }
+    Cave::new(grid, Some(y_max))
+}
 
-    let cnt = count_sand(&mut grid);
-    Some(cnt)
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut cave = build_cave(input, 1);
+    Some(count_sand(&mut cave))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut cave = build_cave(input, 2);
+    Some(count_sand(&mut cave))
 }
 
 fn main() {
     let input = &advent_of_code::read_file("inputs", 14);
+    if let Some(part) = advent_of_code::debugger::requested_part() {
+        return advent_of_code::debugger::debug(build_cave(input, part));
+    }
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
+
+    // drawn after solving, so the timings above leave it out.
+    for (part, name) in [(1, "cave part one"), (2, "cave part two")] {
+        advent_of_code::visualize(14, name, || {
+            let mut cave = build_cave(input, part);
+            count_sand(&mut cave);
+            cave.render()
+        });
+    }
 }
 
 #[cfg(test)]
@@ -181,12 +264,23 @@ mod tests {
     #[test]
     fn test_part_one() {
         let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_one(&input), None);
+        assert_eq!(part_one(&input), Some(24));
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_two(&input), None);
+        assert_eq!(part_two(&input), Some(93));
+    }
+
+    #[test]
+    fn test_draw_empty_cave() {
+        let grid = Grid {
+            map: HashMap::new(),
+            width: 0,
+            height: 0,
+        };
+        assert_eq!(draw_cave(&grid, None), "");
+        assert_eq!(draw_cave(&grid, Some(3)), "");
     }
 }
@@ -0,0 +1,41 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+
+use advent_of_code::checksums;
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+
+pub const HELP: &str = "\
+Accept the current contents of an input as its expected checksum.
+
+Usage: cargo accept-input <day>
+
+Options:
+  -h, --help                 print this help
+";
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let day = args::day(&mut args)?;
+    args::finish(args)?;
+
+    let input_path = format!("src/inputs/{:02}.txt", day);
+    let contents = fs::read_to_string(&input_path)
+        .map_err(|e| Error::Failed(format!("could not read \"{}\": {}", input_path, e)))?;
+
+    checksums::record_checksum(day, &contents)
+        .map_err(|e| Error::Failed(format!("could not record input checksum: {}", e)))?;
+
+    println!(
+        "🎄 Accepted the current input for day {:02} (checksum {}).",
+        day,
+        &checksums::checksum(&contents)[..12]
+    );
+    Ok(())
+}
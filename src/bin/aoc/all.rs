@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, VISUALIZE_ENV, YEAR};
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::report::{self, DayReport};
+use crate::solve::{run_day, solve_command};
+
+pub const HELP: &str = "\
+Run the optimized solutions of all days and print the total execution time.
+
+Usage: cargo all [options]
+
+Options:
+      --html <dir>           also run the tests and write an html report to <dir>/index.html
+  -h, --help                 print this help
+";
+
+/// the version of the compiler cargo builds the solutions with, e.g. `rustc 1.75.0 (82e1608df 2023-12-21)`.
+fn toolchain() -> Option<String> {
+    // cargo honours `RUSTC`, and rustup resolves toolchain overrides for this directory.
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    match output.status.success() {
+        true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        false => None,
+    }
+}
+
+/// runs the optimized solution for `day`, saving its visualizations to `dir`.
+fn run_visualized(day: u8, dir: &Path) -> Result<Output, Error> {
+    solve_command(day, true)?
+        .env(VISUALIZE_ENV, dir)
+        .output()
+        .map_err(|e| Error::Failed(format!("failed to run solution: {}", e)))
+}
+
+fn run_tests(day: u8) -> Option<bool> {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &format!("{:02}", day)])
+        .output()
+        .ok()
+        .map(|output| output.status.success())
+}
+
+fn write_report(
+    dir: &Path,
+    mut reports: Vec<DayReport>,
+    visualizations: &Path,
+    toolchain: Option<&str>,
+) -> Result<(), Error> {
+    for report in reports.iter_mut() {
+        println!("Testing day {:02}...", report.day);
+        report.tests = run_tests(report.day);
+        report.visualizations = report::read_visualizations(visualizations, report.day);
+    }
+    fs::remove_dir_all(visualizations).ok();
+
+    let path = dir.join("index.html");
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, report::render(YEAR, toolchain, &reports)))
+        .map_err(|e| Error::Failed(format!("could not write report: {}", e)))?;
+    println!("🎄 Wrote report to \"{}\"", path.display());
+    Ok(())
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    // solutions always run optimized, `--release` is accepted for compatibility.
+    args.contains("--release");
+    let html: Option<PathBuf> = args.opt_value_from_str("--html")?;
+    args::finish(args)?;
+
+    let toolchain = toolchain();
+    println!(
+        "{}Toolchain:{} {}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        toolchain
+            .as_deref()
+            .unwrap_or("unknown (could not run rustc)")
+    );
+
+    let visualizations = env::temp_dir().join(format!("aoc_visualize_{}", std::process::id()));
+    let mut reports = vec![];
+    let mut total = 0_f64;
+
+    for day in advent_of_code::DAYS {
+        let cmd = match html {
+            Some(_) => run_visualized(*day, &visualizations)?,
+            None => run_day(*day)?,
+        };
+        let day_number = *day;
+        let day = format!("{:02}", day);
+
+        println!("----------");
+        println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+        println!("----------");
+
+        let output = String::from_utf8(cmd.stdout).unwrap();
+        let is_empty = output.is_empty();
+
+        println!(
+            "{}",
+            if is_empty {
+                "Not solved."
+            } else {
+                output.trim()
+            }
+        );
+
+        if !is_empty {
+            total += advent_of_code::parse_exec_time(&output);
+        }
+
+        reports.push(DayReport {
+            failed: !cmd.status.success(),
+            ..DayReport::from_output(day_number, &output)
+        });
+    }
+
+    println!(
+        "{}Total:{} {}{:.2}ms{}",
+        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    );
+
+    match html {
+        Some(dir) => write_report(&dir, reports, &visualizations, toolchain.as_deref()),
+        None => Ok(()),
+    }
+}
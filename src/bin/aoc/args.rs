@@ -0,0 +1,117 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::Arguments;
+
+/// Error of a subcommand. usage errors exit with code 2, failures with code 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<pico_args::Error> for Error {
+    fn from(e: pico_args::Error) -> Self {
+        Error::Usage(e.to_string())
+    }
+}
+
+pub fn parse_day(val: &str) -> Result<u8, String> {
+    val.parse::<u8>()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or_else(|| format!("\"{}\" is not a day between 1 and 25", val))
+}
+
+pub fn parse_part(val: &str) -> Result<u8, String> {
+    val.parse::<u8>()
+        .ok()
+        .filter(|p| (1..=2).contains(p))
+        .ok_or_else(|| format!("\"{}\" is not a part, use 1 or 2", val))
+}
+
+pub fn parse_year(val: &str) -> Result<i16, String> {
+    val.parse::<i16>()
+        .ok()
+        .filter(|y| *y >= 2015)
+        .ok_or_else(|| format!("\"{}\" is not an Advent of Code year", val))
+}
+
+/// prints `help` and returns `true` if `-h` or `--help` was passed.
+pub fn help(args: &mut Arguments, help: &str) -> bool {
+    let requested = args.contains(["-h", "--help"]);
+    if requested {
+        print!("{}", help);
+    }
+    requested
+}
+
+pub fn year(args: &mut Arguments) -> Result<Option<i16>, Error> {
+    Ok(args.opt_value_from_fn(["-y", "--year"], parse_year)?)
+}
+
+/// parses the free-standing day argument. options need to be consumed before.
+pub fn day(args: &mut Arguments) -> Result<u8, Error> {
+    match args.free_from_fn(parse_day) {
+        Err(pico_args::Error::MissingArgument) => {
+            Err(Error::Usage("need to specify a day (as integer)".into()))
+        }
+        result => Ok(result?),
+    }
+}
+
+/// rejects arguments that were not consumed by the subcommand.
+pub fn finish(args: Arguments) -> Result<(), Error> {
+    let remaining = args.finish();
+    match remaining.is_empty() {
+        true => Ok(()),
+        false => Err(Error::Usage(format!(
+            "unexpected arguments: {}",
+            remaining
+                .iter()
+                .map(|a| a.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(args: &[&str]) -> Arguments {
+        Arguments::from_vec(args.iter().map(|a| a.into()).collect())
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("7"), Ok(7));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("26").is_err());
+        assert!(parse_day("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse_year("2022"), Ok(2022));
+        assert!(parse_year("2014").is_err());
+        assert!(parse_year("22x").is_err());
+    }
+
+    #[test]
+    fn test_day_and_year() {
+        let mut args = arguments(&["--year", "2021", "12"]);
+        assert_eq!(year(&mut args), Ok(Some(2021)));
+        assert_eq!(day(&mut args), Ok(12));
+        assert_eq!(finish(args), Ok(()));
+
+        assert!(matches!(day(&mut arguments(&[])), Err(Error::Usage(_))));
+        assert!(matches!(day(&mut arguments(&["30"])), Err(Error::Usage(_))));
+        assert!(matches!(
+            finish(arguments(&["--unknown"])),
+            Err(Error::Usage(_))
+        ));
+    }
+}
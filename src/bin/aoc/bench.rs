@@ -0,0 +1,86 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::solve::run_day;
+
+pub const HELP: &str = "\
+Run optimized solutions several times and report their execution times.
+
+Usage: cargo aoc bench [day] [options]
+
+Options:
+  -r, --runs <n>             number of runs per day (default: 10)
+  -h, --help                 print this help
+";
+
+/// minimum, mean and maximum of the measured times.
+fn stats(times: &[f64]) -> (f64, f64, f64) {
+    let min = times.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = times.iter().cloned().fold(0_f64, f64::max);
+    let mean = times.iter().sum::<f64>() / times.len() as f64;
+    (min, mean, max)
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let runs: usize = args.opt_value_from_str(["-r", "--runs"])?.unwrap_or(10);
+    let day = args.opt_free_from_fn(args::parse_day)?;
+    args::finish(args)?;
+
+    if runs == 0 {
+        return Err(Error::Usage("need at least one run".into()));
+    }
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => advent_of_code::DAYS.to_vec(),
+    };
+
+    println!("| Day | Min | Mean | Max |");
+    println!("| :---: | ---: | ---: | ---: |");
+
+    let mut total = 0_f64;
+    for day in days {
+        let mut times = vec![];
+        for _ in 0..runs {
+            let output = run_day(day)?;
+            if !output.status.success() {
+                return Err(Error::Failed(format!("solution for day {} failed", day)));
+            }
+            times.push(advent_of_code::parse_exec_time(&String::from_utf8_lossy(
+                &output.stdout,
+            )));
+        }
+
+        let (min, mean, max) = stats(&times);
+        total += mean;
+        println!(
+            "| {:02} | {:.3}ms | {:.3}ms | {:.3}ms |",
+            day, min, mean, max
+        );
+    }
+
+    println!(
+        "{}Total (mean):{} {:.3}ms over {} runs per day",
+        ANSI_BOLD, ANSI_RESET, total, runs
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(stats(&[2.0, 1.0, 3.0]), (1.0, 2.0, 3.0));
+        assert_eq!(stats(&[0.5]), (0.5, 0.5, 0.5));
+    }
+}
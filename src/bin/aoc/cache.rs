@@ -0,0 +1,248 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// minimum time between two requests to the Advent of Code servers.
+pub const MIN_REQUEST_DELAY: Duration = Duration::from_secs(5);
+
+/// directory holding the throttle state and cached responses. not checked into git.
+pub fn cache_dir() -> PathBuf {
+    env::current_dir().unwrap().join(".aoc_cache")
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// A request throttle shared by all commands: the timestamp of the last request is persisted
+/// to a file, so repeated invocations wait for each other as well.
+pub struct Throttle {
+    path: PathBuf,
+    min_delay: Duration,
+}
+
+impl Throttle {
+    pub fn new() -> Self {
+        Throttle::at(cache_dir().join("last_request"), MIN_REQUEST_DELAY)
+    }
+
+    pub fn at(path: PathBuf, min_delay: Duration) -> Self {
+        Throttle { path, min_delay }
+    }
+
+    /// blocks until `min_delay` passed since the last recorded request, then records a new one.
+    /// holds a lock file meanwhile, so concurrent invocations take turns.
+    pub fn wait(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let _lock = Lock::acquire(&self.path.with_extension("lock"))?;
+
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok());
+
+        if let Some(last) = last {
+            // timestamps are truncated to milliseconds, so err on the side of waiting longer.
+            let elapsed = Duration::from_millis(now_millis().saturating_sub(last + 1));
+            if elapsed < self.min_delay {
+                let remaining = self.min_delay - elapsed;
+                println!(
+                    "Waiting {:.1}s before the next request...",
+                    remaining.as_secs_f32()
+                );
+                thread::sleep(remaining);
+            }
+        }
+
+        fs::write(&self.path, now_millis().to_string())
+    }
+}
+
+/// A lock file created with `create_new`, removed again on drop.
+struct Lock {
+    path: PathBuf,
+}
+
+impl Lock {
+    /// locks held longer than this are left over from a killed process.
+    const STALE_AFTER: Duration = Duration::from_secs(60);
+
+    fn acquire(path: &Path) -> io::Result<Self> {
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(path) {
+                Ok(_) => {
+                    return Ok(Lock {
+                        path: path.to_path_buf(),
+                    })
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    let stale = fs::metadata(path)
+                        .and_then(|m| m.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .is_some_and(|age| age > Lock::STALE_AFTER);
+                    if stale {
+                        fs::remove_file(path).ok();
+                    } else {
+                        thread::sleep(Duration::from_millis(50));
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        fs::remove_file(&self.path).ok();
+    }
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Throttle::new()
+    }
+}
+
+/// An on-disk cache of responses, keyed by relative paths such as `2022/01/input.txt`.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Cache::at(cache_dir())
+    }
+
+    pub fn at(dir: PathBuf) -> Self {
+        Cache { dir }
+    }
+
+    /// returns the cached contents for `key`. empty entries count as missing.
+    pub fn get(&self, key: &str) -> Option<String> {
+        fs::read_to_string(self.dir.join(key))
+            .ok()
+            .filter(|contents| !contents.trim().is_empty())
+    }
+
+    pub fn put(&self, key: &str, contents: &str) -> io::Result<()> {
+        let path = self.dir.join(key);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, contents)
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Cache::new()
+    }
+}
+
+pub fn input_key(year: i16, day: u8) -> String {
+    format!("{}/{:02}/input.txt", year, day)
+}
+
+pub fn puzzle_key(year: i16, day: u8) -> String {
+    format!("{}/{:02}/puzzle.md", year, day)
+}
+
+/// answers are escaped like `CMZ_201_2F2` for `CMZ 1/2`: other bytes than ascii letters and
+/// digits become `_` and their hex code, so different answers never share an entry.
+pub fn submission_key(year: i16, day: u8, part: u8, answer: &str) -> String {
+    let answer: String = answer
+        .bytes()
+        .map(|b| match b.is_ascii_alphanumeric() {
+            true => (b as char).to_string(),
+            false => format!("_{:02X}", b),
+        })
+        .collect();
+    format!("{}/{:02}/submit-{}-{}.txt", year, day, part, answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+    use std::time::Instant;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc_cache_{}_{}", name, process::id()));
+        fs::remove_dir_all(&path).ok();
+        path
+    }
+
+    #[test]
+    fn test_cache() {
+        let cache = Cache::at(temp_path("cache"));
+        let key = input_key(2022, 1);
+
+        assert_eq!(cache.get(&key), None);
+        cache.put(&key, "1000\n").unwrap();
+        assert_eq!(cache.get(&key), Some("1000\n".to_string()));
+
+        cache.put(&key, "").unwrap();
+        assert_eq!(cache.get(&key), None);
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(input_key(2022, 1), "2022/01/input.txt");
+        assert_eq!(puzzle_key(2022, 12), "2022/12/puzzle.md");
+        assert_eq!(
+            submission_key(2022, 5, 1, "CMZ 1/2"),
+            "2022/05/submit-1-CMZ_201_2F2.txt"
+        );
+        assert_ne!(
+            submission_key(2022, 5, 1, "1-2"),
+            submission_key(2022, 5, 1, "1_2")
+        );
+    }
+
+    #[test]
+    fn test_throttle_is_persisted() {
+        let path = temp_path("throttle").join("last_request");
+        let delay = Duration::from_millis(200);
+
+        let start = Instant::now();
+        Throttle::at(path.clone(), delay).wait().unwrap();
+        assert!(start.elapsed() < delay);
+
+        // a second throttle reading the same file waits for the first request.
+        Throttle::at(path, delay).wait().unwrap();
+        assert!(start.elapsed() >= delay);
+    }
+
+    #[test]
+    fn test_throttle_concurrent() {
+        let path = temp_path("throttle_concurrent").join("last_request");
+        let delay = Duration::from_millis(200);
+
+        // two waits at once must not both pass immediately.
+        let start = Instant::now();
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let path = path.clone();
+                thread::spawn(move || Throttle::at(path, delay).wait().unwrap())
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(start.elapsed() >= delay);
+        assert!(!path.with_extension("lock").exists());
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+
+pub const HELP: &str = "\
+List days whose tests are missing or still assert `None`.
+
+Usage: cargo check-tests
+
+Options:
+  -h, --help                 print this help
+";
+
+enum TestStatus {
+    Missing,
+    Placeholder,
+    Ok,
+}
+
+fn test_status(module: &str, part: &str) -> TestStatus {
+    let assertion = format!("assert_eq!({}(", part);
+
+    match module.lines().find(|l| l.contains(&assertion)) {
+        Some(line) if line.contains("None);") => TestStatus::Placeholder,
+        Some(_) => TestStatus::Ok,
+        None => TestStatus::Missing,
+    }
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    args::finish(args)?;
+
+    let answers = advent_of_code::read_expected_answers();
+    let mut issues = 0;
+
+    for day in 1..=25 {
+        let module_path = format!("src/bin/{:02}.rs", day);
+
+        let module = match fs::read_to_string(&module_path) {
+            Ok(module) => module,
+            Err(_) => continue,
+        };
+
+        for (i, part) in ["part_one", "part_two"].iter().enumerate() {
+            let expected = answers.get(&day).and_then(|parts| parts[i].as_ref());
+            let hint = match expected {
+                Some(answer) => format!(" (expected: {})", answer),
+                None => String::new(),
+            };
+
+            match test_status(&module, part) {
+                TestStatus::Missing => {
+                    println!("Day {:02}: test for {} is missing{}", day, part, hint);
+                    issues += 1;
+                }
+                TestStatus::Placeholder => {
+                    println!("Day {:02}: test for {} asserts `None`{}", day, part, hint);
+                    issues += 1;
+                }
+                TestStatus::Ok => {}
+            }
+        }
+    }
+
+    println!("---");
+    match issues {
+        0 => {
+            println!("🎄 All tests assert real values.");
+            Ok(())
+        }
+        _ => Err(Error::Failed(format!(
+            "🎄 Found {} tests that need attention.",
+            issues
+        ))),
+    }
+}
@@ -0,0 +1,170 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cache::Throttle;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = "github.com/sebous/adventofcode2022 via ureq";
+
+/// reads the session cookie from `AOC_SESSION` or, if unset, from `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .ok_or("could not determine home directory")?;
+    let path = home.join(".adventofcode.session");
+
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(format!("session file \"{}\" is empty", path.display())),
+        Err(e) => Err(format!(
+            "no session found: set AOC_SESSION or create \"{}\" ({})",
+            path.display(),
+            e
+        )),
+    }
+}
+
+/// A minimal HTTP client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(session: &str) -> Self {
+        Client::with_base_url(session, BASE_URL, Throttle::new())
+    }
+
+    pub fn with_base_url(session: &str, base_url: &str, throttle: Throttle) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            throttle,
+        }
+    }
+
+    fn get(&mut self, path: &str) -> Result<String, String> {
+        self.throttle
+            .wait()
+            .map_err(|e| format!("could not record request time: {}", e))?;
+
+        let url = format!("{}{}", self.base_url, path);
+        let response = ureq::get(&url)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("could not read response from {}: {}", url, e)),
+            Err(ureq::Error::Status(code, _)) => {
+                Err(format!("request to {} failed with status {}", url, code))
+            }
+            Err(e) => Err(format!("request to {} failed: {}", url, e)),
+        }
+    }
+
+    pub fn get_input(&mut self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    fn throttle(name: &str, min_delay: Duration) -> Throttle {
+        let path = env::temp_dir().join(format!(
+            "aoc_client_throttle_{}_{}",
+            name,
+            std::process::id()
+        ));
+        fs::remove_file(&path).ok();
+        Throttle::at(path, min_delay)
+    }
+
+    /// starts a mock server answering `responses` in order. returns its url and the received requests.
+    fn mock_server(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                requests.push(request);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_get_input() {
+        let (url, server) = mock_server(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\n1000\n2000\n",
+        ]);
+        let mut client = Client::with_base_url("abc", &url, throttle("input", Duration::ZERO));
+
+        assert_eq!(client.get_input(2022, 1), Ok("1000\n2000\n".to_string()));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=abc"));
+        assert!(requests[0].contains(&format!("User-Agent: {}", USER_AGENT)));
+    }
+
+    #[test]
+    fn test_get_input_error_status() {
+        let (url, server) = mock_server(vec![
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+        let mut client = Client::with_base_url("abc", &url, throttle("status", Duration::ZERO));
+
+        let result = client.get_input(2022, 25);
+        assert!(result.unwrap_err().contains("status 404"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_min_delay() {
+        let ok = "HTTP/1.1 200 OK\r\nContent-Length: 1\r\nConnection: close\r\n\r\n1";
+        let (url, server) = mock_server(vec![ok, ok]);
+        let mut client =
+            Client::with_base_url("abc", &url, throttle("delay", Duration::from_millis(200)));
+
+        let start = Instant::now();
+        client.get_input(2022, 1).unwrap();
+        client.get_input(2022, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+}
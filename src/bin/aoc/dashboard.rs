@@ -0,0 +1,629 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::{cursor, execute, queue, terminal};
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::cache;
+use crate::solve::solve_command;
+use crate::stars::{self, DayStars};
+
+pub const HELP: &str = "\
+Show all days in an interactive dashboard.
+
+Usage: cargo dashboard
+
+Keys:
+  arrows, h j k l            select a day
+  r                          run the solution of the selected day
+  t                          run the tests of the selected day
+  e / i                      view the example / input of the selected day
+  o                          view the output of the last job
+  page up, page down         scroll the bottom pane
+  q, esc                     quit
+
+Options:
+  -h, --help                 print this help
+";
+
+const COLUMNS: u8 = 5;
+/// width of a calendar cell without its border.
+const CELL_WIDTH: usize = 14;
+
+/// Status of a day, collected from the submission log and the results of earlier runs.
+#[derive(Debug, Default, PartialEq)]
+struct DayStatus {
+    day: u8,
+    scaffolded: bool,
+    stars: [bool; 2],
+    answers: [Option<String>; 2],
+    time: Option<f64>,
+    tests: Option<bool>,
+}
+
+/// results of dashboard runs are kept between sessions.
+fn runs_dir() -> PathBuf {
+    cache::cache_dir().join("runs")
+}
+
+fn run_output_path(day: u8) -> PathBuf {
+    runs_dir().join(format!("{:02}.txt", day))
+}
+
+fn test_result_path(day: u8) -> PathBuf {
+    runs_dir().join(format!("{:02}.tests", day))
+}
+
+fn day_status(
+    day: u8,
+    scaffolded: bool,
+    stars: &[DayStars],
+    run_output: Option<&str>,
+    tests: Option<bool>,
+) -> DayStatus {
+    DayStatus {
+        day,
+        scaffolded,
+        stars: stars
+            .iter()
+            .find(|d| d.day == day)
+            .map_or([false; 2], |d| d.stars),
+        answers: [
+            run_output.and_then(|o| advent_of_code::parse_answer(o, 1)),
+            run_output.and_then(|o| advent_of_code::parse_answer(o, 2)),
+        ],
+        time: run_output.map(advent_of_code::parse_exec_time),
+        tests,
+    }
+}
+
+fn load_status(day: u8, stars: &[DayStars]) -> DayStatus {
+    let scaffolded = advent_of_code::DAYS.contains(&day);
+    let run_output = fs::read_to_string(run_output_path(day)).ok();
+    let tests = fs::read_to_string(test_result_path(day))
+        .ok()
+        .map(|r| r.trim() == "passed");
+    day_status(day, scaffolded, stars, run_output.as_deref(), tests)
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
+
+/// removes ANSI escape sequences, which would break truncation and layout.
+fn strip_ansi(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            // skip until the final byte of the sequence.
+            for ch in chars.by_ref() {
+                if ch.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else if ch != '\r' {
+            result.push(ch);
+        }
+    }
+    result
+}
+
+/// renders the three lines of a calendar cell, each `CELL_WIDTH` wide.
+fn render_cell(status: &DayStatus) -> [String; 3] {
+    let stars: String = status
+        .stars
+        .iter()
+        .map(|s| if *s { '*' } else { '.' })
+        .collect();
+    let title = match status.scaffolded {
+        true => format!("Day {:02}  {}", status.day, stars),
+        false => format!("Day {:02}  --", status.day),
+    };
+
+    let answers = match &status.answers {
+        [None, None] => String::new(),
+        [a, b] => format!(
+            "{} / {}",
+            a.as_deref().unwrap_or("-"),
+            b.as_deref().unwrap_or("-")
+        ),
+    };
+
+    let time = status
+        .time
+        .map(|t| format!("{:.2}ms", t))
+        .unwrap_or_default();
+    let tests = match status.tests {
+        Some(true) => "ok",
+        Some(false) => "FAIL",
+        None => "",
+    };
+    let details = format!("{:<9}{:>5}", time, tests);
+
+    [title, answers, details]
+        .map(|line| format!("{:<w$}", truncate(&line, CELL_WIDTH), w = CELL_WIDTH))
+}
+
+/// moves the selection within the 5x5 calendar grid, staying within days 1 to 25.
+fn move_selection(selected: u8, key: KeyCode) -> u8 {
+    let index = selected - 1;
+    let (row, col) = (index / COLUMNS, index % COLUMNS);
+    let (row, col) = match key {
+        KeyCode::Left | KeyCode::Char('h') => (row, col.saturating_sub(1)),
+        KeyCode::Right | KeyCode::Char('l') => (row, (col + 1).min(COLUMNS - 1)),
+        KeyCode::Up | KeyCode::Char('k') => (row.saturating_sub(1), col),
+        KeyCode::Down | KeyCode::Char('j') => ((row + 1).min(24 / COLUMNS), col),
+        _ => (row, col),
+    };
+    row * COLUMNS + col + 1
+}
+
+enum JobKind {
+    Run,
+    Test,
+}
+
+enum Message {
+    Stdout(String),
+    Stderr(String),
+    Done(bool),
+}
+
+/// A solution or test run whose output is streamed to the dashboard.
+struct Job {
+    day: u8,
+    kind: JobKind,
+    stdout: String,
+    rx: Receiver<Message>,
+}
+
+fn forward_lines(reader: impl Read, tx: Sender<Message>, wrap: fn(String) -> Message) {
+    for line in BufReader::new(reader).lines().map_while(Result::ok) {
+        if tx.send(wrap(line)).is_err() {
+            break;
+        }
+    }
+}
+
+fn spawn_job(day: u8, kind: JobKind) -> Result<Job, String> {
+    let mut cmd = match kind {
+        JobKind::Run => solve_command(day, true).map_err(|e| match e {
+            Error::Usage(e) | Error::Failed(e) => e,
+        })?,
+        JobKind::Test => {
+            let mut cmd = Command::new("cargo");
+            cmd.args(["test", "--bin", &format!("{:02}", day)]);
+            cmd
+        }
+    };
+
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to spawn cargo: {}", e))?;
+
+    let (tx, rx) = mpsc::channel();
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+
+    let stderr_tx = tx.clone();
+    thread::spawn(move || {
+        let stderr_thread =
+            thread::spawn(move || forward_lines(stderr, stderr_tx, Message::Stderr));
+        forward_lines(stdout, tx.clone(), Message::Stdout);
+        stderr_thread.join().ok();
+        let success = child.wait().map(|s| s.success()).unwrap_or(false);
+        tx.send(Message::Done(success)).ok();
+    });
+
+    Ok(Job {
+        day,
+        kind,
+        stdout: String::new(),
+        rx,
+    })
+}
+
+enum Pane {
+    Output,
+    File(String),
+}
+
+struct App {
+    selected: u8,
+    statuses: Vec<DayStatus>,
+    pane: Pane,
+    output: Vec<String>,
+    file: Vec<String>,
+    scroll: usize,
+    job: Option<Job>,
+    message: String,
+}
+
+impl App {
+    fn new() -> Self {
+        let mut app = App {
+            selected: 1,
+            statuses: vec![],
+            pane: Pane::Output,
+            output: vec![],
+            file: vec![],
+            scroll: 0,
+            job: None,
+            message: String::new(),
+        };
+        app.reload();
+        app
+    }
+
+    fn reload(&mut self) {
+        let stars = stars::read_stars();
+        self.statuses = (1..=25).map(|day| load_status(day, &stars)).collect();
+    }
+
+    fn start(&mut self, kind: JobKind) {
+        if let Some(job) = &self.job {
+            self.message = format!("day {:02} is still running.", job.day);
+            return;
+        }
+        let label = match kind {
+            JobKind::Run => "Running",
+            JobKind::Test => "Testing",
+        };
+        match spawn_job(self.selected, kind) {
+            Ok(job) => {
+                self.message = format!("{} day {:02}...", label, self.selected);
+                self.output.clear();
+                self.pane = Pane::Output;
+                self.scroll = 0;
+                self.job = Some(job);
+            }
+            Err(e) => self.message = e,
+        }
+    }
+
+    fn view(&mut self, folder: &str) {
+        let path = Path::new("src")
+            .join(folder)
+            .join(format!("{:02}.txt", self.selected));
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                self.file = contents.lines().map(String::from).collect();
+                self.pane = Pane::File(path.display().to_string());
+                self.scroll = 0;
+                self.message.clear();
+            }
+            Err(e) => self.message = format!("could not read \"{}\": {}", path.display(), e),
+        }
+    }
+
+    /// processes the messages of the running job. returns `true` if anything changed.
+    fn poll_job(&mut self) -> bool {
+        let mut changed = false;
+        let mut done = None;
+
+        if let Some(job) = &mut self.job {
+            while let Ok(message) = job.rx.try_recv() {
+                changed = true;
+                match message {
+                    Message::Stdout(line) => {
+                        job.stdout.push_str(&line);
+                        job.stdout.push('\n');
+                        self.output.push(strip_ansi(&line));
+                    }
+                    Message::Stderr(line) => self.output.push(strip_ansi(&line)),
+                    Message::Done(success) => done = Some(success),
+                }
+            }
+        }
+
+        if let Some(success) = done {
+            let job = self.job.take().unwrap();
+            self.finish(job, success);
+        }
+        changed
+    }
+
+    fn finish(&mut self, job: Job, success: bool) {
+        let result = fs::create_dir_all(runs_dir()).and_then(|_| match job.kind {
+            JobKind::Run if success => fs::write(run_output_path(job.day), &job.stdout),
+            JobKind::Run => Ok(()),
+            JobKind::Test => fs::write(
+                test_result_path(job.day),
+                if success { "passed" } else { "failed" },
+            ),
+        });
+
+        self.message = match (result, success) {
+            (Err(e), _) => format!("could not store the result: {}", e),
+            (Ok(_), true) => format!("day {:02} finished.", job.day),
+            (Ok(_), false) => format!("day {:02} failed.", job.day),
+        };
+        self.reload();
+    }
+
+    /// handles a key press. returns `false` if the dashboard should quit.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('r') => self.start(JobKind::Run),
+            KeyCode::Char('t') => self.start(JobKind::Test),
+            KeyCode::Char('e') => self.view("examples"),
+            KeyCode::Char('i') => self.view("inputs"),
+            KeyCode::Char('o') => {
+                self.pane = Pane::Output;
+                self.scroll = 0;
+            }
+            KeyCode::PageUp | KeyCode::PageDown => {
+                let towards_start = key.code == KeyCode::PageUp;
+                let scroll_back = matches!(self.pane, Pane::Output) == towards_start;
+                self.scroll = match scroll_back {
+                    true => self.scroll + 10,
+                    false => self.scroll.saturating_sub(10),
+                };
+            }
+            code => self.selected = move_selection(self.selected, code),
+        }
+        true
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        let mut row = 0;
+
+        draw_line(
+            out,
+            &mut row,
+            width,
+            &format!(
+                " Advent of Code {}  [r]un [t]est [e]xample [i]nput [o]utput [q]uit",
+                advent_of_code::YEAR
+            ),
+            Attribute::Bold,
+        )?;
+
+        let border = format!(
+            "+{}",
+            format!("{}+", "-".repeat(CELL_WIDTH)).repeat(COLUMNS as usize)
+        );
+
+        for week in self.statuses.chunks(COLUMNS as usize) {
+            draw_line(out, &mut row, width, &border, Attribute::Reset)?;
+            let cells: Vec<[String; 3]> = week.iter().map(render_cell).collect();
+            for i in 0..3 {
+                queue!(
+                    out,
+                    cursor::MoveTo(0, row as u16),
+                    terminal::Clear(terminal::ClearType::CurrentLine),
+                    Print("|")
+                )?;
+                for (status, cell) in week.iter().zip(&cells) {
+                    let attribute = match (status.day == self.selected, status.scaffolded) {
+                        (true, _) => Attribute::Reverse,
+                        (false, false) => Attribute::Dim,
+                        (false, true) => Attribute::Reset,
+                    };
+                    queue!(
+                        out,
+                        SetAttribute(attribute),
+                        Print(&cell[i]),
+                        SetAttribute(Attribute::Reset),
+                        Print("|")
+                    )?;
+                }
+                row += 1;
+            }
+        }
+        draw_line(out, &mut row, width, &border, Attribute::Reset)?;
+        draw_line(
+            out,
+            &mut row,
+            width,
+            &format!(" {}", self.message),
+            Attribute::Italic,
+        )?;
+
+        let (title, lines) = match &self.pane {
+            Pane::Output => ("output".to_string(), &self.output),
+            Pane::File(path) => (path.clone(), &self.file),
+        };
+        draw_line(
+            out,
+            &mut row,
+            width,
+            &format!("--- {} ---", title),
+            Attribute::Bold,
+        )?;
+
+        // output follows its tail and scrolls up, files start at the top and scroll down.
+        let visible = height.saturating_sub(row);
+        let (start, end) = match self.pane {
+            Pane::Output => {
+                let end = lines.len().saturating_sub(self.scroll);
+                (end.saturating_sub(visible), end)
+            }
+            Pane::File(_) => {
+                let start = self.scroll.min(lines.len());
+                (start, (start + visible).min(lines.len()))
+            }
+        };
+        for text in &lines[start..end] {
+            draw_line(out, &mut row, width, text, Attribute::Reset)?;
+        }
+        while row < height {
+            draw_line(out, &mut row, width, "", Attribute::Reset)?;
+        }
+
+        out.flush()
+    }
+}
+
+fn draw_line(
+    out: &mut impl Write,
+    row: &mut usize,
+    width: usize,
+    text: &str,
+    attribute: Attribute,
+) -> io::Result<()> {
+    queue!(
+        out,
+        cursor::MoveTo(0, *row as u16),
+        terminal::Clear(terminal::ClearType::CurrentLine),
+        SetAttribute(attribute),
+        Print(truncate(text, width)),
+        SetAttribute(Attribute::Reset)
+    )?;
+    *row += 1;
+    Ok(())
+}
+
+/// restores the terminal when the dashboard exits, also on errors and panics.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen).ok();
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+fn event_loop(app: &mut App) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    let mut dirty = true;
+
+    loop {
+        dirty |= app.poll_job();
+        if dirty {
+            app.draw(&mut stdout)?;
+            dirty = false;
+        }
+
+        if event::poll(Duration::from_millis(100))? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    if !app.handle_key(key) {
+                        return Ok(());
+                    }
+                    dirty = true;
+                }
+                Event::Resize(_, _) => dirty = true,
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    args::finish(args)?;
+
+    let mut app = App::new();
+    let guard = TerminalGuard::enter()
+        .map_err(|e| Error::Failed(format!("could not set up the terminal: {}", e)))?;
+    let result = event_loop(&mut app);
+    drop(guard);
+
+    result.map_err(|e| Error::Failed(format!("dashboard failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    fn run_output() -> String {
+        format!(
+            "🎄 {b}Part 1{r} 🎄\n24000 {i}(elapsed: 1.50ms){r}\n🎄 {b}Part 2{r} 🎄\nnot solved.\n",
+            b = ANSI_BOLD,
+            i = ANSI_ITALIC,
+            r = ANSI_RESET
+        )
+    }
+
+    #[test]
+    fn test_day_status() {
+        let stars = [DayStars {
+            day: 1,
+            stars: [true, false],
+        }];
+        let status = day_status(1, true, &stars, Some(&run_output()), Some(true));
+
+        assert_eq!(
+            status,
+            DayStatus {
+                day: 1,
+                scaffolded: true,
+                stars: [true, false],
+                answers: [Some("24000".to_string()), None],
+                time: Some(1.5),
+                tests: Some(true),
+            }
+        );
+    }
+
+    #[test]
+    fn test_render_cell() {
+        let stars = [DayStars {
+            day: 1,
+            stars: [true, false],
+        }];
+        let status = day_status(1, true, &stars, Some(&run_output()), Some(false));
+        assert_eq!(
+            render_cell(&status),
+            [
+                "Day 01  *.    ".to_string(),
+                "24000 / -     ".to_string(),
+                "1.50ms    FAIL".to_string()
+            ]
+        );
+
+        let empty = day_status(20, false, &[], None, None);
+        assert_eq!(render_cell(&empty)[0], "Day 20  --    ");
+        assert_eq!(render_cell(&empty)[1], " ".repeat(CELL_WIDTH));
+    }
+
+    #[test]
+    fn test_move_selection() {
+        assert_eq!(move_selection(1, KeyCode::Left), 1);
+        assert_eq!(move_selection(1, KeyCode::Right), 2);
+        assert_eq!(move_selection(1, KeyCode::Char('j')), 6);
+        assert_eq!(move_selection(5, KeyCode::Right), 5);
+        assert_eq!(move_selection(23, KeyCode::Down), 23);
+        assert_eq!(move_selection(8, KeyCode::Up), 3);
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(
+            strip_ansi(&format!("{}Part 1{}\r", ANSI_BOLD, ANSI_RESET)),
+            "Part 1"
+        );
+    }
+}
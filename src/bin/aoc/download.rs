@@ -0,0 +1,568 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env::temp_dir, io, process::Command};
+use std::{fs, process};
+
+use advent_of_code::checksums;
+use advent_of_code::puzzles;
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::cache::{self, Cache, Throttle};
+use crate::client::{self, Client};
+
+pub const HELP: &str = "\
+Download puzzle inputs, using aoc-cli if installed and the built-in client otherwise.
+
+Usage: cargo download <day|from-to> [options]
+       cargo download --all [options]
+
+Options:
+  -y, --year <year>          year of the puzzle
+      --all                  download all unlocked days
+      --force                re-download days that already have an input
+      --puzzle               also download the puzzle description (requires aoc-cli)
+      --native               use the built-in client even if aoc-cli is installed
+  -h, --help                 print this help
+";
+
+enum Days {
+    Single(u8),
+    Range(u8, u8),
+    All,
+}
+
+struct Args {
+    days: Days,
+    year: Option<i16>,
+    force: bool,
+    puzzle: bool,
+    native: bool,
+}
+
+enum Status {
+    Downloaded,
+    Skipped,
+    Failed(String),
+}
+
+fn parse_days(val: &str) -> Result<Days, String> {
+    match val.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (args::parse_day(from)?, args::parse_day(to)?);
+            if from > to {
+                return Err(format!("range \"{}\" is empty", val));
+            }
+            Ok(Days::Range(from, to))
+        }
+        None => Ok(Days::Single(args::parse_day(val)?)),
+    }
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, Error> {
+    let year = args::year(&mut args)?;
+    let force = args.contains("--force");
+    let puzzle = args.contains("--puzzle");
+    let native = args.contains("--native");
+    let days = if args.contains("--all") {
+        Days::All
+    } else {
+        match args.free_from_fn(parse_days) {
+            Err(pico_args::Error::MissingArgument) => {
+                return Err(Error::Usage(
+                    "need to specify a day or range of days".into(),
+                ));
+            }
+            days => days?,
+        }
+    };
+    args::finish(args)?;
+    Ok(Args {
+        days,
+        year,
+        force,
+        puzzle,
+        native,
+    })
+}
+
+/// returns the number of days of `year` that are unlocked, based on the current time.
+/// puzzles unlock at midnight EST (UTC-5).
+fn unlocked_days(year: i16) -> u8 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let (current_year, month, day) = civil_from_days((now - 5 * 3600).div_euclid(86400));
+
+    if i64::from(year) < current_year {
+        25
+    } else if i64::from(year) == current_year && month == 12 {
+        day.min(25) as u8
+    } else {
+        0
+    }
+}
+
+/// converts days since 1970-01-01 to a (year, month, day) date.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// a temp directory unique to this invocation, removed when dropped.
+struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    fn new() -> io::Result<Self> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        let path = temp_dir().join(format!("aoc_download_{}_{}", process::id(), nanos));
+        // `create_dir` fails if the directory exists, so no other invocation shares it.
+        fs::create_dir(&path)?;
+        Ok(TempDir { path })
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        #[allow(unused_must_use)]
+        {
+            fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+/// checks that a downloaded input is usable before it gets installed.
+fn validate_input(contents: &str) -> Result<(), String> {
+    let start = contents.trim_start().to_lowercase();
+    if start.is_empty() {
+        Err("downloaded input is empty".into())
+    } else if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        Err("downloaded input looks like an HTML page, is the session cookie valid?".into())
+    } else {
+        Ok(())
+    }
+}
+
+/// writes `contents` to `path` atomically by writing a sibling temp file and renaming it.
+fn install_file(path: &Path, contents: &str) -> io::Result<()> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, process::id()));
+
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        #[allow(unused_must_use)]
+        {
+            fs::remove_file(&tmp_path);
+        }
+    })
+}
+
+/// downloads the input and/or the puzzle description of `day` with aoc-cli.
+fn fetch_with_aoc_cli(
+    day: u8,
+    year: i16,
+    input: bool,
+    puzzle: bool,
+) -> Result<(Option<String>, Option<String>), String> {
+    // acquire a temp file path to write aoc-cli output to.
+    let tmp_dir = TempDir::new().map_err(|e| format!("could not create temp directory: {}", e))?;
+    let tmp_file_path = tmp_dir.path.join("input");
+    let tmp_puzzle_path = tmp_dir.path.join("puzzle.md");
+
+    let mut cmd_args = vec!["--year".into(), year.to_string()];
+
+    if input {
+        cmd_args.push("--input-file".into());
+        cmd_args.push(tmp_file_path.to_string_lossy().to_string());
+    } else {
+        cmd_args.push("--puzzle-only".into());
+    }
+
+    if puzzle {
+        cmd_args.push("--puzzle-file".into());
+        cmd_args.push(tmp_puzzle_path.to_string_lossy().to_string());
+    }
+
+    cmd_args.append(&mut vec![
+        "--day".into(),
+        day.to_string(),
+        "download".into(),
+    ]);
+
+    Throttle::new()
+        .wait()
+        .map_err(|e| format!("could not record request time: {}", e))?;
+
+    println!("Downloading with >aoc {}", cmd_args.join(" "));
+
+    match Command::new("aoc").args(cmd_args).output() {
+        Ok(cmd_output) => {
+            io::stdout()
+                .write_all(&cmd_output.stdout)
+                .expect("could not write cmd stdout to pipe.");
+            io::stderr()
+                .write_all(&cmd_output.stderr)
+                .expect("could not write cmd stderr to pipe.");
+            if !cmd_output.status.success() {
+                return Err(format!("aoc-cli exited with {}", cmd_output.status));
+            }
+        }
+        Err(e) => {
+            return Err(format!("failed to spawn aoc-cli: {}", e));
+        }
+    }
+
+    let contents = match input {
+        true => Some(
+            fs::read_to_string(&tmp_file_path)
+                .map_err(|e| format!("could not read downloaded input: {}", e))?,
+        ),
+        false => None,
+    };
+
+    let description = match puzzle {
+        true => Some(
+            fs::read_to_string(&tmp_puzzle_path)
+                .map_err(|e| format!("could not read downloaded puzzle description: {}", e))?,
+        ),
+        false => None,
+    };
+
+    Ok((contents, description))
+}
+
+/// downloads the input of `day` with the built-in HTTP client.
+fn fetch_native(client: &mut Client, day: u8, year: i16) -> Result<String, String> {
+    println!("Downloading input for day {}, {}...", day, year);
+    client.get_input(year, day)
+}
+
+/// stores downloaded responses in the cache. puzzle descriptions are only cached once they
+/// contain part two, as the description grows when part one is solved.
+fn cache_responses(
+    cache: &Cache,
+    year: i16,
+    day: u8,
+    contents: Option<&str>,
+    description: Option<&str>,
+) -> Result<(), String> {
+    if let Some(contents) = contents {
+        cache
+            .put(&cache::input_key(year, day), contents)
+            .map_err(|e| format!("could not cache input: {}", e))?;
+    }
+
+    if let Some(description) = description.filter(|d| d.contains("--- Part Two ---")) {
+        cache
+            .put(&cache::puzzle_key(year, day), description)
+            .map_err(|e| format!("could not cache puzzle description: {}", e))?;
+    }
+    Ok(())
+}
+
+/// downloads the input and/or the puzzle description of `day`, whichever is requested.
+/// responses are taken from the cache where possible.
+fn download(
+    day: u8,
+    year: Option<i16>,
+    input: bool,
+    puzzle: bool,
+    client: Option<&mut Client>,
+) -> Result<(), String> {
+    let input_path = format!("src/inputs/{:02}.txt", day);
+
+    let cache = Cache::new();
+    let year = year.unwrap_or(advent_of_code::YEAR);
+    let cached_input = match input {
+        true => cache.get(&cache::input_key(year, day)),
+        false => None,
+    };
+    let cached_puzzle = match puzzle {
+        true => cache.get(&cache::puzzle_key(year, day)),
+        false => None,
+    };
+    let fetch_input = input && cached_input.is_none();
+    let fetch_puzzle = puzzle && cached_puzzle.is_none();
+
+    if cached_input.is_some() || cached_puzzle.is_some() {
+        println!("Using cached response for day {}, {}.", day, year);
+    }
+
+    let (fetched_input, fetched_puzzle) = match (fetch_input, fetch_puzzle) {
+        (false, false) => (None, None),
+        _ => {
+            let (contents, description) = match client {
+                Some(_) if fetch_puzzle => {
+                    return Err("downloading puzzle descriptions requires aoc-cli".into());
+                }
+                Some(client) => (Some(fetch_native(client, day, year)?), None),
+                None => fetch_with_aoc_cli(day, year, fetch_input, fetch_puzzle)?,
+            };
+            if let Some(contents) = &contents {
+                validate_input(contents)?;
+            }
+            cache_responses(
+                &cache,
+                year,
+                day,
+                contents.as_deref(),
+                description.as_deref(),
+            )?;
+            (contents, description)
+        }
+    };
+
+    if let Some(contents) = fetched_input.or(cached_input) {
+        install_file(Path::new(&input_path), &contents)
+            .map_err(|e| format!("could not write downloaded input to input file: {}", e))?;
+        checksums::record_checksum(day, &contents)
+            .map_err(|e| format!("could not record input checksum: {}", e))?;
+    }
+
+    let description = fetched_puzzle.or(cached_puzzle);
+    if let Some(description) = description {
+        if description.trim().is_empty() {
+            return Err("downloaded puzzle description is empty".into());
+        }
+
+        let puzzle_path = puzzles::puzzle_path(day);
+        if let Some(dir) = puzzle_path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create puzzles directory: {}", e))?;
+        }
+        install_file(&puzzle_path, &description)
+            .map_err(|e| format!("could not write puzzle description: {}", e))?;
+        println!("Wrote puzzle description to \"{}\".", puzzle_path.display());
+
+        install_examples(day, &description)?;
+    }
+
+    Ok(())
+}
+
+/// writes the examples found in a puzzle description to `src/examples/` and fills in proposed
+/// example answers that are not known yet. example files with contents are left untouched.
+fn install_examples(day: u8, description: &str) -> Result<(), String> {
+    for (i, example) in puzzles::extract_examples(description).iter().enumerate() {
+        let example_path = match i {
+            0 => format!("src/examples/{:02}.txt", day),
+            _ => format!("src/examples/{:02}-{}.txt", day, i + 1),
+        };
+        let has_example = fs::metadata(&example_path)
+            .map(|m| m.len() > 0)
+            .unwrap_or(false);
+
+        if has_example {
+            println!("Kept existing example file \"{}\".", example_path);
+            continue;
+        }
+        install_file(Path::new(&example_path), example)
+            .map_err(|e| format!("could not write example file: {}", e))?;
+        println!("Wrote example to \"{}\".", example_path);
+    }
+
+    let answers = puzzles::extract_answers(description);
+    if answers.iter().all(|a| a.is_none()) {
+        return Ok(());
+    }
+
+    println!(
+        "Proposed example answers: part one {}, part two {}.",
+        answers[0].as_deref().unwrap_or("-"),
+        answers[1].as_deref().unwrap_or("-")
+    );
+
+    let answers_path = "src/examples/answers.txt";
+    let contents = fs::read_to_string(answers_path).unwrap_or_default();
+    let merged = advent_of_code::merge_expected_answers(&contents, day, &answers);
+    if merged != contents {
+        install_file(Path::new(answers_path), &merged)
+            .map_err(|e| format!("could not write expected answers: {}", e))?;
+        println!(
+            "Added unknown answers to \"{}\", check them before scaffolding.",
+            answers_path
+        );
+    }
+    Ok(())
+}
+
+/// downloads the input and puzzle description for `day` if they are missing, or both if `force`
+/// is set. existing files are left untouched otherwise.
+fn download_missing(
+    day: u8,
+    year: Option<i16>,
+    force: bool,
+    puzzle: bool,
+    client: Option<&mut Client>,
+) -> Status {
+    let input_path = format!("src/inputs/{:02}.txt", day);
+    let has_input = fs::metadata(&input_path)
+        .map(|m| m.len() > 0)
+        .unwrap_or(false);
+    let has_puzzle = puzzles::puzzle_path(day).exists();
+
+    if has_input && (has_puzzle || !puzzle) && !force {
+        println!("Skipping day {}: \"{}\" already exists.", day, input_path);
+        return Status::Skipped;
+    }
+    if has_input && !force {
+        println!("Kept existing input file \"{}\".", input_path);
+    }
+
+    match download(
+        day,
+        year,
+        !has_input || force,
+        puzzle && (!has_puzzle || force),
+        client,
+    ) {
+        Ok(_) => Status::Downloaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            Status::Failed(e)
+        }
+    }
+}
+
+fn print_summary(results: &[(u8, Status)]) {
+    println!("---");
+    println!("| Day | Status |");
+    println!("| :---: | :--- |");
+    for (day, status) in results {
+        let status = match status {
+            Status::Downloaded => "downloaded".to_string(),
+            Status::Skipped => "skipped".to_string(),
+            Status::Failed(e) => format!("failed: {}", e),
+        };
+        println!("| {:02} | {} |", day, status);
+    }
+
+    let count = |f: fn(&Status) -> bool| results.iter().filter(|(_, s)| f(s)).count();
+    println!(
+        "🎄 {} downloaded, {} skipped, {} failed.",
+        count(|s| matches!(s, Status::Downloaded)),
+        count(|s| matches!(s, Status::Skipped)),
+        count(|s| matches!(s, Status::Failed(_)))
+    );
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let args = parse_args(args)?;
+
+    // use aoc-cli if it exists and is callable, the built-in client otherwise.
+    let mut client = if args.native || Command::new("aoc").arg("-V").output().is_err() {
+        match client::read_session() {
+            Ok(session) => Some(Client::new(&session)),
+            Err(e) if args.native => return Err(Error::Failed(e)),
+            Err(e) => {
+                return Err(Error::Failed(format!(
+                    "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it, or provide a session for the built-in client: {}",
+                    e
+                )));
+            }
+        }
+    } else {
+        None
+    };
+
+    let days = match args.days {
+        Days::Single(day) => {
+            download(day, args.year, true, args.puzzle, client.as_mut()).map_err(Error::Failed)?;
+            println!("---");
+            println!(
+                "🎄 Successfully wrote input to \"src/inputs/{:02}.txt\".",
+                day
+            );
+            return Ok(());
+        }
+        Days::Range(from, to) => from..=to,
+        Days::All => 1..=unlocked_days(args.year.unwrap_or(advent_of_code::YEAR)),
+    };
+
+    let results: Vec<(u8, Status)> = days
+        .map(|day| {
+            let status = download_missing(day, args.year, args.force, args.puzzle, client.as_mut());
+            (day, status)
+        })
+        .collect();
+
+    print_summary(&results);
+
+    let failed = results
+        .iter()
+        .filter(|(_, s)| matches!(s, Status::Failed(_)))
+        .count();
+    match failed {
+        0 => Ok(()),
+        _ => Err(Error::Failed(format!("{} downloads failed.", failed))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert!(matches!(parse_days("7"), Ok(Days::Single(7))));
+        assert!(matches!(parse_days("3-12"), Ok(Days::Range(3, 12))));
+        assert!(parse_days("12-3").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("0-4").is_err());
+    }
+
+    #[test]
+    fn test_validate_input() {
+        assert!(validate_input("1000\n2000\n").is_ok());
+        assert!(validate_input("").is_err());
+        assert!(validate_input("  \n").is_err());
+        assert!(validate_input("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(validate_input("<html><body>500 Internal Server Error</body></html>").is_err());
+    }
+
+    #[test]
+    fn test_temp_dirs_are_unique() {
+        let a = TempDir::new().unwrap();
+        let b = TempDir::new().unwrap();
+        assert_ne!(a.path, b.path);
+        let path = a.path.clone();
+        drop(a);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19327), (2022, 12, 1));
+        assert_eq!(civil_from_days(19351), (2022, 12, 25));
+    }
+
+    #[test]
+    fn test_unlocked_days() {
+        assert_eq!(unlocked_days(2015), 25);
+        assert_eq!(unlocked_days(i16::MAX), 0);
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use advent_of_code::crypto;
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::verify::ANSWERS_FILE;
+
+pub const HELP: &str = "\
+Encrypt the inputs in src/inputs and its day directories with the key in
+.aoc_key (or AOC_KEY_FILE).
+
+Usage: cargo encrypt [options]
+
+Options:
+      --init                 create a new key file
+      --decrypt              restore the plaintext inputs from the encrypted files
+  -h, --help                 print this help
+";
+
+struct Args {
+    init: bool,
+    decrypt: bool,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, Error> {
+    let init = args.contains("--init");
+    let decrypt = args.contains("--decrypt");
+    args::finish(args)?;
+    Ok(Args { init, decrypt })
+}
+
+/// returns `dir` and the directories of additional inputs (`src/inputs/NN/`) in it.
+fn input_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    dirs.retain(|p| p.is_dir());
+    dirs.sort();
+    dirs.insert(0, dir.to_path_buf());
+    dirs
+}
+
+/// returns the plaintext input files (`src/inputs/NN.txt`), sorted by name.
+fn input_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    files.retain(|p| {
+        p.extension().is_some_and(|ext| ext == "txt")
+            && p.file_name().is_some_and(|name| name != ANSWERS_FILE)
+    });
+    files.sort();
+    files
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+/// writes `contents` to `path` unless the file already has these contents.
+fn write_if_changed(path: &Path, contents: &str) -> Result<bool, String> {
+    if fs::read_to_string(path).is_ok_and(|c| c == contents) {
+        return Ok(false);
+    }
+    fs::write(path, contents)
+        .map(|_| true)
+        .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+}
+
+fn encrypt_all(key: &crypto::Key, dir: &Path) -> Result<(), String> {
+    for path in input_files(dir) {
+        let name = file_name(&path);
+        let plaintext = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read \"{}\": {}", path.display(), e))?;
+        let encrypted_path = crypto::encrypted_path(&path);
+
+        match write_if_changed(&encrypted_path, &crypto::encrypt(key, &name, &plaintext))? {
+            true => println!("Encrypted \"{}\".", encrypted_path.display()),
+            false => println!("\"{}\" is up to date.", encrypted_path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn decrypt_all(key: &crypto::Key, dir: &Path) -> Result<(), String> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("could not read \"{}\": {}", dir.display(), e))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.extension()
+                .is_some_and(|ext| ext == crypto::ENCRYPTED_EXTENSION)
+        })
+        .collect();
+    files.sort();
+
+    for encrypted_path in files {
+        let path = encrypted_path.with_extension("");
+        let name = file_name(&path);
+        let ciphertext = fs::read_to_string(&encrypted_path)
+            .map_err(|e| format!("could not read \"{}\": {}", encrypted_path.display(), e))?;
+
+        match write_if_changed(&path, &crypto::decrypt(key, &name, &ciphertext)?)? {
+            true => println!("Decrypted \"{}\".", path.display()),
+            false => println!("\"{}\" is up to date.", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn init_key() -> Result<(), String> {
+    let path = crypto::key_path();
+    if path.exists() {
+        return Err(format!("key file \"{}\" already exists", path.display()));
+    }
+    fs::write(&path, crypto::generate_key()? + "\n")
+        .map_err(|e| format!("could not write key file \"{}\": {}", path.display(), e))?;
+    println!(
+        "Created key file \"{}\". Share it with your team, but never commit it.",
+        path.display()
+    );
+    Ok(())
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let args = parse_args(args)?;
+
+    if args.init {
+        return init_key().map_err(Error::Failed);
+    }
+
+    let key = crypto::read_key().map_err(Error::Failed)?;
+
+    for dir in input_dirs(Path::new("src/inputs")) {
+        let result = match args.decrypt {
+            true => decrypt_all(&key, &dir),
+            false => encrypt_all(&key, &dir),
+        };
+        result.map_err(Error::Failed)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+use pico_args::Arguments;
+
+use args::Error;
+
+mod accept;
+mod all;
+mod args;
+mod bench;
+mod cache;
+mod check;
+mod client;
+mod dashboard;
+mod download;
+mod encrypt;
+mod read;
+mod report;
+mod scaffold;
+mod serve;
+mod solve;
+mod stars;
+mod status;
+mod submit;
+mod verify;
+
+const HELP: &str = "\
+Advent of Code helper.
+
+Usage: cargo aoc <command> [options]
+
+Commands:
+  scaffold       create the files for a day
+  download       download puzzle inputs
+  solve          run the solution for a day
+  all            run the solutions of all days
+  bench          measure the execution times of solutions
+  check          list days whose tests still need work
+  verify         run a solution against several inputs
+  dashboard      show all days in an interactive dashboard
+  serve          serve the solutions over HTTP
+  stars          regenerate the stars table in the readme
+  status         print the collected stars
+  submit         submit an answer
+  read           render a puzzle description
+  encrypt        encrypt or decrypt inputs
+  accept-input   accept the current contents of an input
+
+Run `cargo aoc <command> --help` for the options of a command.
+";
+
+fn main() {
+    let mut args = Arguments::from_env();
+
+    let subcommand = match args.subcommand() {
+        Ok(subcommand) => subcommand,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    let result = match subcommand.as_deref() {
+        Some("scaffold") => scaffold::run(args),
+        Some("download") => download::run(args),
+        Some("solve") => solve::run(args),
+        Some("all") => all::run(args),
+        Some("bench") => bench::run(args),
+        Some("check") => check::run(args),
+        Some("verify") => verify::run(args),
+        Some("dashboard") => dashboard::run(args),
+        Some("serve") => serve::run(args),
+        Some("stars") => stars::run(args),
+        Some("status") => status::run(args),
+        Some("submit") => submit::run(args),
+        Some("read") => read::run(args),
+        Some("encrypt") => encrypt::run(args),
+        Some("accept-input") => accept::run(args),
+        Some("help") => {
+            print!("{}", HELP);
+            Ok(())
+        }
+        Some(other) => Err(Error::Usage(format!("unknown command \"{}\"", other))),
+        None if args.contains(["-h", "--help"]) => {
+            print!("{}", HELP);
+            Ok(())
+        }
+        None => Err(Error::Usage("need to specify a command".into())),
+    };
+
+    match result {
+        Ok(()) => {}
+        Err(Error::Usage(e)) => {
+            eprintln!("error: {}", e);
+            eprintln!("Run `cargo aoc --help` for usage.");
+            process::exit(2);
+        }
+        Err(Error::Failed(e)) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::puzzles;
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+
+pub const HELP: &str = "\
+Render a downloaded puzzle description in the terminal.
+
+Usage: cargo read <day>
+
+Options:
+  -h, --help                 print this help
+";
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let day = args::day(&mut args)?;
+    args::finish(args)?;
+
+    match puzzles::read_puzzle(day) {
+        Ok(markdown) => {
+            print!("{}", puzzles::render_markdown(&markdown));
+            Ok(())
+        }
+        Err(e) => Err(Error::Failed(format!(
+            "could not read \"{}\": {}. Try running `cargo download {} --puzzle` first.",
+            puzzles::puzzle_path(day).display(),
+            e,
+            day
+        ))),
+    }
+}
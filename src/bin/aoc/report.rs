@@ -0,0 +1,228 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::path::Path;
+
+/// Results of a day, as shown in the html report.
+#[derive(Debug, Default)]
+pub struct DayReport {
+    pub day: u8,
+    pub failed: bool,
+    pub answers: [Option<String>; 2],
+    pub times: [Option<f64>; 2],
+    pub tests: Option<bool>,
+    /// pictures saved with `advent_of_code::visualize`, as pairs of name and picture.
+    pub visualizations: Vec<(String, String)>,
+}
+
+impl DayReport {
+    pub fn from_output(day: u8, output: &str) -> Self {
+        DayReport {
+            day,
+            answers: [1, 2].map(|part| advent_of_code::parse_answer(output, part)),
+            times: [1, 2].map(|part| advent_of_code::parse_part_time(output, part)),
+            ..Default::default()
+        }
+    }
+
+    fn time(&self) -> Option<f64> {
+        match self.times {
+            [None, None] => None,
+            [a, b] => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+        }
+    }
+}
+
+/// reads the visualizations `day` saved to `dir`, sorted by name.
+pub fn read_visualizations(dir: &Path, day: u8) -> Vec<(String, String)> {
+    let prefix = format!("{:02}-", day);
+    let mut visualizations: Vec<(String, String)> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let name = file_name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+            let picture = fs::read_to_string(dir.join(&file_name)).ok()?;
+            Some((name.replace('-', " "), picture))
+        })
+        .collect();
+    visualizations.sort();
+    visualizations
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #ccc; background: #0f0f23; }
+h1, h2 { color: #00cc00; }
+table { border-collapse: collapse; width: 100%; }
+th, td { padding: 0.3em 0.6em; text-align: left; border-bottom: 1px solid #333; }
+code { color: #fff; }
+.missing { color: #666; }
+.passed { color: #00cc00; }
+.failed { color: #ff4444; }
+.time { width: 35%; }
+.bar { display: inline-block; height: 0.8em; min-width: 1px; margin-right: 0.5em; background: #ffff66; }
+figure { margin: 1em 0; }
+pre { font-size: 8px; line-height: 1; overflow-x: auto; color: #fff; }
+";
+
+fn answer_cell(answer: &Option<String>) -> String {
+    match answer {
+        Some(answer) => format!("<td><code>{}</code></td>", escape(answer)),
+        None => "<td class=\"missing\">not solved</td>".into(),
+    }
+}
+
+fn time_cell(report: &DayReport, max_time: f64) -> String {
+    match (report.failed, report.time()) {
+        (true, _) => "<td class=\"time failed\">failed</td>".into(),
+        (false, Some(time)) => format!(
+            "<td class=\"time\"><span class=\"bar\" style=\"width: {:.1}%\"></span>{:.3}ms</td>",
+            // leave room for the label next to the bar.
+            time / max_time * 75.0,
+            time
+        ),
+        (false, None) => "<td class=\"time missing\">-</td>".into(),
+    }
+}
+
+fn tests_cell(tests: Option<bool>) -> &'static str {
+    match tests {
+        Some(true) => "<td class=\"passed\">passed</td>",
+        Some(false) => "<td class=\"failed\">failed</td>",
+        None => "<td class=\"missing\">not run</td>",
+    }
+}
+
+/// renders a self-contained page: styles are inlined and the bar chart is plain css.
+pub fn render(year: i16, toolchain: Option<&str>, days: &[DayReport]) -> String {
+    let max_time = days
+        .iter()
+        .filter_map(DayReport::time)
+        .fold(0_f64, f64::max)
+        .max(f64::MIN_POSITIVE);
+    let total: f64 = days.iter().filter_map(DayReport::time).sum();
+
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code {0}</title>\n<style>{1}</style>\n</head>\n<body>\n<h1>🎄 Advent of Code {0}</h1>\n<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Time</th><th>Tests</th></tr>\n",
+        year, STYLE
+    );
+
+    for report in days {
+        let day = match report.visualizations.is_empty() {
+            true => format!("Day {}", report.day),
+            false => format!("<a href=\"#day-{0}\">Day {0}</a>", report.day),
+        };
+        html.push_str(&format!(
+            "<tr><td>{}</td>{}{}{}{}</tr>\n",
+            day,
+            answer_cell(&report.answers[0]),
+            answer_cell(&report.answers[1]),
+            time_cell(report, max_time),
+            tests_cell(report.tests)
+        ));
+    }
+    html.push_str(&format!("</table>\n<p>Total: {:.2}ms</p>\n", total));
+    if let Some(toolchain) = toolchain {
+        html.push_str(&format!(
+            "<p>Built with <code>{}</code></p>\n",
+            escape(toolchain)
+        ));
+    }
+
+    for report in days.iter().filter(|r| !r.visualizations.is_empty()) {
+        html.push_str(&format!("<h2 id=\"day-{0}\">Day {0}</h2>\n", report.day));
+        for (name, picture) in &report.visualizations {
+            html.push_str(&format!(
+                "<figure>\n<figcaption>{}</figcaption>\n<pre>{}</pre>\n</figure>\n",
+                escape(name),
+                escape(picture.trim_end())
+            ));
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_from_output() {
+        let output = format!(
+            "🎄 Part 1{r} 🎄\n13140 (elapsed: 2.00ms)\n🎄 Part 2{r} 🎄\nnot solved.\n",
+            r = advent_of_code::ANSI_RESET
+        );
+        let report = DayReport::from_output(10, &output);
+        assert_eq!(report.answers, [Some("13140".to_string()), None]);
+        assert_eq!(report.time(), Some(2.0));
+        assert_eq!(DayReport::default().time(), None);
+    }
+
+    #[test]
+    fn test_read_visualizations() {
+        let dir = env::temp_dir().join(format!("aoc_report_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("14-cave-part-two.txt"), "#+#").unwrap();
+        fs::write(dir.join("14-cave-part-one.txt"), "#.#").unwrap();
+        fs::write(dir.join("10-CRT-image.txt"), "##..").unwrap();
+
+        assert_eq!(
+            read_visualizations(&dir, 14),
+            vec![
+                ("cave part one".to_string(), "#.#".to_string()),
+                ("cave part two".to_string(), "#+#".to_string())
+            ]
+        );
+        assert!(read_visualizations(&dir, 1).is_empty());
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_render() {
+        let days = [
+            DayReport {
+                day: 1,
+                answers: [Some("24000".into()), Some("<45000>".into())],
+                times: [Some(1.0), Some(1.0)],
+                tests: Some(true),
+                ..Default::default()
+            },
+            DayReport {
+                day: 10,
+                answers: [Some("13140".into()), None],
+                times: [Some(4.0), None],
+                tests: Some(false),
+                visualizations: vec![("CRT image".into(), "##..\n".into())],
+                ..Default::default()
+            },
+            DayReport {
+                day: 11,
+                failed: true,
+                ..Default::default()
+            },
+        ];
+        let html = render(2022, Some("rustc 1.75.0 (82e1608df 2023-12-21)"), &days);
+
+        assert!(html.contains("<code>&lt;45000&gt;</code>"));
+        assert!(html.contains("style=\"width: 37.5%\"></span>2.000ms"));
+        assert!(html.contains("style=\"width: 75.0%\"></span>4.000ms"));
+        assert!(html.contains("<td class=\"time failed\">failed</td>"));
+        assert!(html.contains("<a href=\"#day-10\">Day 10</a>"));
+        assert!(html.contains("<figcaption>CRT image</figcaption>\n<pre>##..</pre>"));
+        assert!(html.contains("<p>Total: 6.00ms</p>"));
+        assert!(html.contains("<p>Built with <code>rustc 1.75.0 (82e1608df 2023-12-21)</code></p>"));
+        assert!(!html.contains("http"));
+    }
+}
@@ -0,0 +1,706 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    process::Command,
+    str::FromStr,
+};
+
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+
+pub const HELP: &str = "\
+Create the solution, example and input files for a day.
+
+Usage: cargo scaffold <day> [options]
+
+Options:
+  -y, --year <year>          year of the puzzle
+  -t, --template <name>      template to use: plain, parse, grid or templates/<name>.txt
+      --title <title>        puzzle title written to the solution header
+      --type <type>          answer type: u32, u64, usize, i32, i64 or string.
+                             defaults to the first of u32, u64, i64 and string
+                             that fits the expected answers
+      --git                  stage created and updated files
+      --dry-run              print the planned changes without writing them
+      --force                overwrite an existing solution, keeping a .bak copy
+  -h, --help                 print this help
+";
+
+/// the parts of a built-in template that are shared by all templates. `{{body}}` is replaced
+/// with the part functions of the template.
+const BASE_TEMPLATE: &str = r###"// {{title}}: https://adventofcode.com/{{year}}/day/{{day}}
+{{body}}
+fn main() {
+    let input = &advent_of_code::read_file("inputs", {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{day}});
+        assert_eq!(part_one(&input), {{expected_part_one}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{day}});
+        assert_eq!(part_two(&input), {{expected_part_two}});
+    }
+}
+"###;
+
+const PLAIN_BODY: &str = r###"pub fn part_one(input: &str) -> Option<{{answer_type}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
+    None
+}
+"###;
+
+const PARSE_BODY: &str = r###"fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{answer_type}}> {
+    let data = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
+    let data = parse(input);
+    None
+}
+"###;
+
+const GRID_BODY: &str = r###"use advent_of_code::helpers::Grid;
+
+fn parse(input: &str) -> Grid<char> {
+    Grid::from_input(input, |ch| ch)
+}
+
+pub fn part_one(input: &str) -> Option<{{answer_type}}> {
+    let grid = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer_type}}> {
+    let grid = parse(input);
+    None
+}
+"###;
+
+const TEMPLATES_DIR: &str = "templates";
+const REGISTRY_PATH: &str = "src/lib.rs";
+const REGISTRY_DECLARATION: &str = "pub const DAYS: &[u8] = &[";
+pub const README_PATH: &str = "README.md";
+pub const README_TABLE_MARKER: &str = "<!--- advent_readme_stars table --->";
+const ANSWERS_PATH: &str = "src/examples/answers.txt";
+
+#[derive(Clone, Copy, PartialEq)]
+enum AnswerType {
+    U32,
+    U64,
+    Usize,
+    I32,
+    I64,
+    String,
+}
+
+impl FromStr for AnswerType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "u32" => Ok(AnswerType::U32),
+            "u64" => Ok(AnswerType::U64),
+            "usize" => Ok(AnswerType::Usize),
+            "i32" => Ok(AnswerType::I32),
+            "i64" => Ok(AnswerType::I64),
+            "string" => Ok(AnswerType::String),
+            _ => Err(format!(
+                "unknown answer type \"{}\", expected one of u32|u64|usize|i32|i64|string",
+                s
+            )),
+        }
+    }
+}
+
+impl AnswerType {
+    fn rust_type(&self) -> &'static str {
+        match self {
+            AnswerType::U32 => "u32",
+            AnswerType::U64 => "u64",
+            AnswerType::Usize => "usize",
+            AnswerType::I32 => "i32",
+            AnswerType::I64 => "i64",
+            AnswerType::String => "String",
+        }
+    }
+
+    fn accepts(&self, answer: &str) -> bool {
+        match self {
+            AnswerType::U32 => answer.parse::<u32>().is_ok(),
+            AnswerType::U64 | AnswerType::Usize => answer.parse::<u64>().is_ok(),
+            AnswerType::I32 => answer.parse::<i32>().is_ok(),
+            AnswerType::I64 => answer.parse::<i64>().is_ok(),
+            AnswerType::String => true,
+        }
+    }
+
+    /// picks the narrowest type the expected answers fit in, `u32` if there are none.
+    fn infer(answers: &[Option<String>]) -> Self {
+        [AnswerType::U32, AnswerType::U64, AnswerType::I64]
+            .into_iter()
+            .find(|t| answers.iter().flatten().all(|a| t.accepts(a)))
+            .unwrap_or(AnswerType::String)
+    }
+
+    fn format_expected(&self, answer: &Option<String>) -> Result<String, String> {
+        match (answer, self) {
+            (None, _) => Ok("None".to_string()),
+            (Some(answer), AnswerType::String) => Ok(format!("Some({:?}.to_string())", answer)),
+            (Some(answer), t) if t.accepts(answer) => Ok(format!("Some({})", answer)),
+            (Some(answer), t) => Err(format!(
+                "expected answer \"{}\" is not a valid {}. Pick another type with `--type`.",
+                answer,
+                t.rust_type()
+            )),
+        }
+    }
+}
+
+struct Args {
+    day: u8,
+    year: Option<i16>,
+    template: String,
+    title: Option<String>,
+    /// `None` if the type is inferred from the expected answers.
+    answer_type: Option<AnswerType>,
+    stage: bool,
+    dry_run: bool,
+    force: bool,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, Error> {
+    // options need to be consumed before the free-standing day argument.
+    let year = args::year(&mut args)?;
+    let template = args
+        .opt_value_from_str(["-t", "--template"])?
+        .unwrap_or_else(|| "plain".to_string());
+    let title = args.opt_value_from_str("--title")?;
+    let answer_type = args.opt_value_from_str("--type")?;
+    let stage = args.contains("--git");
+    let dry_run = args.contains("--dry-run");
+    let force = args.contains("--force");
+    let day = args::day(&mut args)?;
+    args::finish(args)?;
+    Ok(Args {
+        day,
+        year,
+        template,
+        title,
+        answer_type,
+        stage,
+        dry_run,
+        force,
+    })
+}
+
+/// loads a template from the `templates/` directory, falling back to the built-in templates.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{}.txt", name));
+
+    if path.exists() {
+        return fs::read_to_string(&path)
+            .map_err(|e| format!("could not read template \"{}\": {}", path.display(), e));
+    }
+
+    let body = match name {
+        "plain" => PLAIN_BODY,
+        "parse" => PARSE_BODY,
+        "grid" => GRID_BODY,
+        _ => {
+            return Err(format!(
+                "unknown template \"{}\". Use one of grid|parse|plain or add \"{}\".",
+                name,
+                path.display()
+            ))
+        }
+    };
+    Ok(BASE_TEMPLATE.replace("{{body}}", body))
+}
+
+/// adds `day` to the `DAYS` registry in `src/lib.rs`. returns `None` if the day is already registered.
+fn register_day(source: &str, day: u8) -> Option<String> {
+    let start = source.find(REGISTRY_DECLARATION)?;
+    let end = start + source[start..].find("];")? + 2;
+
+    let mut days: Vec<u8> = source[start + REGISTRY_DECLARATION.len()..end - 2]
+        .split(',')
+        .filter_map(|d| d.trim().parse().ok())
+        .collect();
+
+    if days.contains(&day) {
+        return None;
+    }
+
+    days.push(day);
+    days.sort_unstable();
+
+    let days = days
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    // mirror rustfmt: keep the list on one line if it fits, break it out otherwise.
+    let single_line = format!("{}{}];", REGISTRY_DECLARATION, days);
+    let declaration = if single_line.len() <= 100 {
+        single_line
+    } else {
+        format!("{}\n    {},\n];", REGISTRY_DECLARATION, days)
+    };
+
+    Some(format!(
+        "{}{}{}",
+        &source[..start],
+        declaration,
+        &source[end..]
+    ))
+}
+
+/// adds an empty row for `day` to the stars table. returns `None` if the row exists.
+fn add_readme_row(readme: &str, day: u8, year: i16) -> Option<String> {
+    let day_link = format!("[Day {}](", day);
+    let row = format!(
+        "| [Day {}](https://adventofcode.com/{}/day/{}) | | |",
+        day, year, day
+    );
+
+    let mut lines: Vec<&str> = readme.lines().collect();
+    let table_start = lines.iter().position(|l| l.trim() == README_TABLE_MARKER)?;
+    let table_end = table_start
+        + 1
+        + lines[table_start + 1..]
+            .iter()
+            .position(|l| l.trim() == README_TABLE_MARKER)?;
+
+    if lines[table_start..table_end]
+        .iter()
+        .any(|l| l.contains(&day_link))
+    {
+        return None;
+    }
+
+    // insert before the first row of a later day to keep the table sorted.
+    let insert_at = (table_start..table_end)
+        .find(|i| {
+            lines[*i]
+                .split("[Day ")
+                .nth(1)
+                .and_then(|rest| rest.split(']').next())
+                .and_then(|d| d.parse::<u8>().ok())
+                .is_some_and(|d| d > day)
+        })
+        .unwrap_or(table_end);
+
+    lines.insert(insert_at, &row);
+    Some(lines.join("\n") + "\n")
+}
+
+/// adds an entry without known answers for `day` to the answers file. returns `None` if the day is listed.
+fn add_answers_entry(answers: &str, day: u8) -> Option<String> {
+    if advent_of_code::parse_expected_answers(answers).contains_key(&day) {
+        return None;
+    }
+
+    Some(advent_of_code::merge_expected_answers(
+        answers,
+        day,
+        &[None, None],
+    ))
+}
+
+/// records every change scaffold makes to the file system so it can be undone if a later step fails.
+struct Transaction {
+    dry_run: bool,
+    created: Vec<String>,
+    modified: Vec<(String, String)>,
+}
+
+impl Transaction {
+    fn new(dry_run: bool) -> Self {
+        Transaction {
+            dry_run,
+            created: vec![],
+            modified: vec![],
+        }
+    }
+
+    /// creates a new file at `path`. fails if the file exists.
+    fn create_file(&mut self, path: &str, contents: &str) -> Result<(), String> {
+        if self.dry_run {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|e| format!("could not create \"{}\": {}", path, e))?;
+        self.created.push(path.to_string());
+        file.write_all(contents.as_bytes())
+            .map_err(|e| format!("could not write \"{}\": {}", path, e))
+    }
+
+    /// replaces the contents of an existing file at `path`.
+    fn overwrite_file(&mut self, path: &str, contents: &str) -> Result<(), String> {
+        if self.dry_run {
+            return Ok(());
+        }
+        let previous =
+            fs::read_to_string(path).map_err(|e| format!("could not read \"{}\": {}", path, e))?;
+        self.modified.push((path.to_string(), previous));
+        fs::write(path, contents).map_err(|e| format!("could not write \"{}\": {}", path, e))
+    }
+
+    /// applies `update` to the file at `path`, writing it only if something changed.
+    fn update_file(
+        &mut self,
+        path: &str,
+        update: impl Fn(&str) -> Option<String>,
+    ) -> Result<bool, String> {
+        let contents = fs::read_to_string(path).unwrap_or_default();
+
+        match update(&contents) {
+            Some(_) if self.dry_run => Ok(true),
+            Some(updated) if Path::new(path).exists() => {
+                self.overwrite_file(path, &updated).map(|_| true)
+            }
+            Some(updated) => self.create_file(path, &updated).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// restores modified files and removes created files, latest change first.
+    fn rollback(self) {
+        for (path, contents) in self.modified.iter().rev() {
+            match fs::write(path, contents) {
+                Ok(_) => eprintln!("Restored \"{}\"", path),
+                Err(e) => eprintln!("Failed to restore \"{}\": {}", path, e),
+            }
+        }
+        for path in self.created.iter().rev() {
+            match fs::remove_file(path) {
+                Ok(_) => eprintln!("Removed \"{}\"", path),
+                Err(e) => eprintln!("Failed to remove \"{}\": {}", path, e),
+            }
+        }
+    }
+}
+
+fn label<'a>(dry_run: bool, done: &'a str, planned: &'a str) -> &'a str {
+    if dry_run {
+        planned
+    } else {
+        done
+    }
+}
+
+fn register(
+    tx: &mut Transaction,
+    path: &str,
+    name: &str,
+    day: u8,
+    update: impl Fn(&str) -> Option<String>,
+) -> Result<(), String> {
+    if tx.update_file(path, update)? {
+        println!(
+            "{} day {} to {} in \"{}\"",
+            label(tx.dry_run, "Added", "Would add"),
+            day,
+            name,
+            path
+        );
+    } else {
+        println!("Day {} is already in {} in \"{}\"", day, name, path);
+    }
+    Ok(())
+}
+
+fn scaffold(args: &Args, template: &str, tx: &mut Transaction) -> Result<(), String> {
+    let day = args.day;
+    let year = args.year.unwrap_or(advent_of_code::YEAR);
+    let day_padded = format!("{:02}", day);
+
+    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let example_path = format!("src/examples/{}.txt", day_padded);
+    let module_path = format!("src/bin/{}.rs", day_padded);
+
+    let [part_one, part_two] = advent_of_code::read_expected_answers()
+        .remove(&day)
+        .unwrap_or_default();
+
+    let title = args.title.clone().unwrap_or_else(|| format!("Day {}", day));
+
+    let answer_type = args.answer_type.unwrap_or_else(|| {
+        let inferred = AnswerType::infer(&[part_one.clone(), part_two.clone()]);
+        if inferred != AnswerType::U32 {
+            println!(
+                "Using answer type {} to fit the expected answers",
+                inferred.rust_type()
+            );
+        }
+        inferred
+    });
+
+    let module = template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{year}}", &year.to_string())
+        .replace("{{title}}", &title)
+        .replace("{{answer_type}}", answer_type.rust_type())
+        .replace(
+            "{{expected_part_one}}",
+            &answer_type.format_expected(&part_one)?,
+        )
+        .replace(
+            "{{expected_part_two}}",
+            &answer_type.format_expected(&part_two)?,
+        );
+
+    if Path::new(&module_path).exists() {
+        if !args.force {
+            return Err(format!(
+                "module file \"{}\" already exists. Use `--force` to overwrite it.",
+                module_path
+            ));
+        }
+        let backup_path = format!("{}.bak", module_path);
+        let previous = fs::read_to_string(&module_path)
+            .map_err(|e| format!("could not read \"{}\": {}", module_path, e))?;
+        if Path::new(&backup_path).exists() {
+            tx.overwrite_file(&backup_path, &previous)?;
+        } else {
+            tx.create_file(&backup_path, &previous)?;
+        }
+        println!(
+            "{} existing module file to \"{}\"",
+            label(tx.dry_run, "Backed up", "Would back up"),
+            &backup_path
+        );
+        tx.overwrite_file(&module_path, &module)?;
+        println!(
+            "{} module file \"{}\"",
+            label(tx.dry_run, "Overwrote", "Would overwrite"),
+            &module_path
+        );
+    } else {
+        tx.create_file(&module_path, &module)?;
+        println!(
+            "{} module file \"{}\"",
+            label(tx.dry_run, "Created", "Would create"),
+            &module_path
+        );
+    }
+
+    for (path, name) in [(&input_path, "input"), (&example_path, "example")] {
+        if Path::new(path).exists() {
+            println!("Kept existing {} file \"{}\"", name, path);
+        } else {
+            tx.create_file(path, "")?;
+            println!(
+                "{} empty {} file \"{}\"",
+                label(tx.dry_run, "Created", "Would create"),
+                name,
+                path
+            );
+        }
+    }
+
+    register(tx, REGISTRY_PATH, "day registry", day, |source| {
+        register_day(source, day)
+    })?;
+    register(tx, README_PATH, "stars table", day, |readme| {
+        add_readme_row(readme, day, year)
+    })?;
+    register(tx, ANSWERS_PATH, "expected answers", day, |answers| {
+        add_answers_entry(answers, day)
+    })?;
+
+    if args.stage {
+        // puzzle inputs are not meant to be committed, so they are not staged.
+        let paths = [
+            module_path.as_str(),
+            example_path.as_str(),
+            REGISTRY_PATH,
+            README_PATH,
+            ANSWERS_PATH,
+        ];
+        if tx.dry_run {
+            println!("Would stage files with `git add {}`", paths.join(" "));
+        } else {
+            match Command::new("git").arg("add").args(paths).status() {
+                Ok(status) if status.success() => println!("Staged files with git"),
+                Ok(status) => return Err(format!("git exited with {}", status)),
+                Err(e) => return Err(format!("failed to spawn git: {}", e)),
+            }
+        }
+    }
+
+    println!("---");
+    if tx.dry_run {
+        println!("🎄 Dry run, nothing was written.");
+    } else {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &day_padded
+        );
+    }
+
+    Ok(())
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let args = parse_args(args)?;
+
+    let template = load_template(&args.template)
+        .map_err(|e| Error::Failed(format!("Failed to load template: {}", e)))?;
+
+    let mut tx = Transaction::new(args.dry_run);
+
+    scaffold(&args, &template, &mut tx).map_err(|e| {
+        tx.rollback();
+        Error::Failed(format!("Failed to scaffold day {}: {}", args.day, e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_type() {
+        let answer = |a: &str| Some(a.to_string());
+
+        assert_eq!(
+            AnswerType::U32.format_expected(&answer("42")),
+            Ok("Some(42)".into())
+        );
+        assert!(AnswerType::U32.format_expected(&answer("CMZ")).is_err());
+        assert!(AnswerType::U32
+            .format_expected(&answer("5000000000"))
+            .is_err());
+        assert!(AnswerType::U64.format_expected(&answer("-1")).is_err());
+        assert_eq!(
+            AnswerType::String.format_expected(&answer("say \"hi\"")),
+            Ok("Some(\"say \\\"hi\\\"\".to_string())".into())
+        );
+        assert_eq!(AnswerType::I64.format_expected(&None), Ok("None".into()));
+
+        assert!(AnswerType::infer(&[None, None]) == AnswerType::U32);
+        assert!(AnswerType::infer(&[answer("CMZ"), answer("MCD")]) == AnswerType::String);
+        assert!(AnswerType::infer(&[answer("5000000000"), None]) == AnswerType::U64);
+        assert!(AnswerType::infer(&[answer("-3"), answer("1")]) == AnswerType::I64);
+    }
+
+    #[test]
+    fn test_load_template() {
+        let grid = load_template("grid").unwrap();
+        assert!(grid.starts_with("// {{title}}: https://adventofcode.com/{{year}}/day/{{day}}\nuse advent_of_code::helpers::Grid;\n"));
+        assert!(grid.contains("    None\n}\n\nfn main() {\n"));
+        assert!(!grid.contains("{{body}}"));
+        assert!(load_template("missing").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let source = "pub const DAYS: &[u8] = &[1, 2, 4];\n";
+        assert_eq!(
+            register_day(source, 3),
+            Some("pub const DAYS: &[u8] = &[1, 2, 3, 4];\n".to_string())
+        );
+        assert_eq!(register_day(source, 2), None);
+
+        let source = format!(
+            "pub const DAYS: &[u8] = &[\n    {},\n];\n",
+            (1..=24)
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let registered = register_day(&source, 25).unwrap();
+        assert!(registered.contains("23, 24, 25,\n];\n"));
+        assert_eq!(register_day(&registered, 25), None);
+    }
+
+    #[test]
+    fn test_add_readme_row() {
+        let readme = format!(
+            "# AoC\n{}\n| Day | Part 1 | Part 2 |\n| [Day 1](x) | ⭐ | ⭐ |\n| [Day 3](x) | ⭐ | |\n{}\n",
+            README_TABLE_MARKER, README_TABLE_MARKER
+        );
+        let updated = add_readme_row(&readme, 2, 2022).unwrap();
+        assert!(updated.contains(
+            "| [Day 1](x) | ⭐ | ⭐ |\n| [Day 2](https://adventofcode.com/2022/day/2) | | |\n| [Day 3](x)"
+        ));
+        assert_eq!(add_readme_row(&updated, 2, 2022), None);
+        assert!(add_readme_row(&readme, 4, 2022)
+            .unwrap()
+            .ends_with("/day/4) | | |\n<!--- advent_readme_stars table --->\n"));
+    }
+
+    #[test]
+    fn test_add_answers_entry() {
+        let answers = "# comment\n01 1 2\n03 5 -\n";
+        assert_eq!(
+            add_answers_entry(answers, 2),
+            Some("# comment\n01 1 2\n02 - -\n03 5 -\n".to_string())
+        );
+        assert_eq!(add_answers_entry(answers, 3), None);
+    }
+
+    #[test]
+    fn test_transaction_rollback() {
+        let dir = std::env::temp_dir().join(format!("aoc_scaffold_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let existing = dir.join("existing.txt").to_string_lossy().to_string();
+        let created = dir.join("created.txt").to_string_lossy().to_string();
+        fs::write(&existing, "before").unwrap();
+
+        let mut tx = Transaction::new(false);
+        tx.overwrite_file(&existing, "after").unwrap();
+        tx.create_file(&created, "new").unwrap();
+        assert!(tx.create_file(&created, "again").is_err());
+        tx.rollback();
+
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "before");
+        assert!(!Path::new(&created).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_transaction_dry_run() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc_scaffold_dry_run_{}", std::process::id()))
+            .to_string_lossy()
+            .to_string();
+
+        let mut tx = Transaction::new(true);
+        tx.create_file(&path, "contents").unwrap();
+        assert_eq!(tx.update_file(&path, |_| Some("x".to_string())), Ok(true));
+        assert!(!Path::new(&path).exists());
+    }
+}
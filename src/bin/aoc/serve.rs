@@ -0,0 +1,434 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code::{parse_answer, parse_part_time, DAYS, INPUT_ENV, PART_ENV, YEAR};
+use pico_args::Arguments;
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::args::{self, Error};
+
+pub const HELP: &str = "\
+Serve the solutions over HTTP.
+
+Usage: cargo serve [options]
+
+Endpoints:
+  GET  /days                      list the days that can be solved
+  POST /days/<day>/parts/<part>   solve a part, the request body is the puzzle input
+
+Options:
+  -p, --port <port>          port to listen on (default: 3000)
+  -t, --timeout <seconds>    time limit of a solution (default: 10)
+  -h, --help                 print this help
+";
+
+const DEFAULT_PORT: u16 = 3000;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// largest accepted puzzle input. real inputs stay well below this.
+const MAX_INPUT_LEN: u64 = 1024 * 1024;
+
+struct State {
+    /// directory holding the optimized day binaries.
+    bin_dir: PathBuf,
+    days: Vec<u8>,
+    timeout: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Route {
+    Days,
+    Solve { day: u8, part: u8 },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum SolveError {
+    Failed(String),
+    TimedOut,
+}
+
+fn parse_timeout(val: &str) -> Result<Duration, String> {
+    val.parse::<f64>()
+        .ok()
+        .filter(|secs| *secs > 0.0)
+        .map(Duration::from_secs_f64)
+        .ok_or_else(|| format!("\"{}\" is not a positive number of seconds", val))
+}
+
+fn route(method: &Method, url: &str) -> Result<Route, (u16, String)> {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let (route, allowed) = match segments.as_slice() {
+        ["days"] => (Route::Days, Method::Get),
+        ["days", day, "parts", part] => {
+            let day = args::parse_day(day).map_err(|e| (404, e))?;
+            let part = args::parse_part(part).map_err(|e| (404, e))?;
+            (Route::Solve { day, part }, Method::Post)
+        }
+        _ => return Err((404, format!("no route for \"{}\"", path))),
+    };
+
+    match *method == allowed {
+        true => Ok(route),
+        false => Err((405, format!("use {} for \"{}\"", allowed, path))),
+    }
+}
+
+fn json_string(val: &str) -> String {
+    let mut json = String::from('"');
+    for ch in val.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch.is_control() => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_error(message: &str) -> String {
+    format!("{{\"error\":{}}}", json_string(message))
+}
+
+/// runs `part` of a day binary on the input at `input`, killing it once `timeout` passed.
+fn run_solution(
+    binary: &Path,
+    part: u8,
+    input: &Path,
+    timeout: Duration,
+) -> Result<String, SolveError> {
+    let mut child = Command::new(binary)
+        .env(INPUT_ENV, input)
+        .env(PART_ENV, part.to_string())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| SolveError::Failed(format!("failed to run solution: {}", e)))?;
+
+    // drain the pipes while waiting, so a chatty solution cannot block on a full pipe.
+    let read_pipe = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            pipe.read_to_end(&mut buf).ok();
+            String::from_utf8_lossy(&buf).into_owned()
+        })
+    };
+    let stdout = read_pipe(Box::new(child.stdout.take().unwrap()));
+    let stderr = read_pipe(Box::new(child.stderr.take().unwrap()));
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() >= timeout => {
+                child.kill().ok();
+                child.wait().ok();
+                return Err(SolveError::TimedOut);
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(5)),
+            Err(e) => return Err(SolveError::Failed(format!("failed to run solution: {}", e))),
+        }
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    match status.success() {
+        true => Ok(stdout),
+        false => Err(SolveError::Failed(format!(
+            "solution exited with {}: {}",
+            status,
+            stderr.trim()
+        ))),
+    }
+}
+
+fn solve(state: &State, day: u8, part: u8, input: &str) -> (u16, String) {
+    if !state.days.contains(&day) {
+        return (404, json_error(&format!("day {} has no solution", day)));
+    }
+
+    // every request gets its own input file, so concurrent requests don't interfere.
+    static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+    let input_path = env::temp_dir().join(format!(
+        "aoc_serve_{}_{}.txt",
+        std::process::id(),
+        REQUESTS.fetch_add(1, Ordering::Relaxed)
+    ));
+    if let Err(e) = fs::write(&input_path, input) {
+        return (500, json_error(&format!("could not write input: {}", e)));
+    }
+
+    let binary = state.bin_dir.join(format!("{:02}", day));
+    let result = run_solution(&binary, part, &input_path, state.timeout);
+    fs::remove_file(&input_path).ok();
+
+    match result {
+        Ok(output) => match (parse_answer(&output, part), parse_part_time(&output, part)) {
+            (Some(answer), Some(time)) => (
+                200,
+                format!(
+                    "{{\"day\":{},\"part\":{},\"answer\":{},\"time_ms\":{:.3}}}",
+                    day,
+                    part,
+                    json_string(&answer),
+                    time
+                ),
+            ),
+            _ => (
+                404,
+                json_error(&format!("day {} part {} is not solved", day, part)),
+            ),
+        },
+        Err(SolveError::Failed(e)) => (500, json_error(&e)),
+        Err(SolveError::TimedOut) => (
+            504,
+            json_error(&format!(
+                "solution did not finish within {:.1}s",
+                state.timeout.as_secs_f32()
+            )),
+        ),
+    }
+}
+
+fn respond(state: &State, method: &Method, url: &str, body: &mut dyn Read) -> (u16, String) {
+    match route(method, url) {
+        Ok(Route::Days) => {
+            let days: Vec<String> = state.days.iter().map(|d| d.to_string()).collect();
+            (
+                200,
+                format!("{{\"year\":{},\"days\":[{}]}}", YEAR, days.join(",")),
+            )
+        }
+        Ok(Route::Solve { day, part }) => {
+            let mut input = String::new();
+            match body.take(MAX_INPUT_LEN + 1).read_to_string(&mut input) {
+                Ok(_) if input.len() as u64 > MAX_INPUT_LEN => {
+                    (413, json_error("puzzle input is too large"))
+                }
+                Ok(_) => solve(state, day, part, &input),
+                Err(e) => (400, json_error(&format!("could not read input: {}", e))),
+            }
+        }
+        Err((status, e)) => (status, json_error(&e)),
+    }
+}
+
+/// builds the optimized binaries of all days and returns the days that compiled.
+fn build_days() -> Vec<u8> {
+    DAYS.iter()
+        .copied()
+        .filter(|day| {
+            let day_padded = format!("{:02}", day);
+            println!("Building day {}...", day_padded);
+            let built = Command::new("cargo")
+                .args(["build", "--release", "--quiet", "--bin", &day_padded])
+                .status()
+                .is_ok_and(|status| status.success());
+            if !built {
+                eprintln!(
+                    "Day {} does not compile and will not be served.",
+                    day_padded
+                );
+            }
+            built
+        })
+        .collect()
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let port = args
+        .opt_value_from_str(["-p", "--port"])?
+        .unwrap_or(DEFAULT_PORT);
+    let timeout = args
+        .opt_value_from_fn(["-t", "--timeout"], parse_timeout)?
+        .unwrap_or(DEFAULT_TIMEOUT);
+    args::finish(args)?;
+
+    let target_dir = env::var_os("CARGO_TARGET_DIR").unwrap_or_else(|| "target".into());
+    let state = Arc::new(State {
+        bin_dir: PathBuf::from(target_dir).join("release"),
+        days: build_days(),
+        timeout,
+    });
+
+    let server = Server::http(("127.0.0.1", port))
+        .map_err(|e| Error::Failed(format!("could not listen on port {}: {}", port, e)))?;
+    println!("---");
+    println!("🎄 Serving solutions on http://127.0.0.1:{}", port);
+
+    for mut request in server.incoming_requests() {
+        let state = Arc::clone(&state);
+        thread::spawn(move || {
+            let method = request.method().clone();
+            let url = request.url().to_string();
+            let (status, body) = respond(&state, &method, &url, request.as_reader());
+            println!("{} {} -> {}", method, url, status);
+
+            let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+            let response = Response::from_string(body)
+                .with_status_code(status)
+                .with_header(content_type);
+            if let Err(e) = request.respond(response) {
+                eprintln!("could not send response: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route(&Method::Get, "/days"), Ok(Route::Days));
+        assert_eq!(
+            route(&Method::Post, "/days/7/parts/2?verbose"),
+            Ok(Route::Solve { day: 7, part: 2 })
+        );
+        assert!(matches!(route(&Method::Post, "/days"), Err((405, _))));
+        assert!(matches!(
+            route(&Method::Get, "/days/7/parts/2"),
+            Err((405, _))
+        ));
+        assert!(matches!(
+            route(&Method::Post, "/days/26/parts/1"),
+            Err((404, _))
+        ));
+        assert!(matches!(
+            route(&Method::Post, "/days/1/parts/3"),
+            Err((404, _))
+        ));
+        assert!(matches!(route(&Method::Get, "/"), Err((404, _))));
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("CMZ"), "\"CMZ\"");
+        assert_eq!(json_string("a\"b\\c\nd\x01"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("x").is_err());
+    }
+
+    #[cfg(unix)]
+    mod solutions {
+        use super::*;
+        use std::os::unix::fs::PermissionsExt;
+
+        /// a state whose day binaries live in a temp directory, removed when dropped.
+        struct FakeState(State);
+
+        impl Drop for FakeState {
+            fn drop(&mut self) {
+                fs::remove_dir_all(&self.0.bin_dir).ok();
+            }
+        }
+
+        /// creates a state whose day binaries are the given shell scripts.
+        fn fake_state(name: &str, days: &[(u8, &str)]) -> FakeState {
+            let dir = env::temp_dir().join(format!("aoc_serve_{}_{}", name, std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            for (day, script) in days {
+                let path = dir.join(format!("{:02}", day));
+                fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+                fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+            }
+            FakeState(State {
+                bin_dir: dir,
+                days: days.iter().map(|(day, _)| *day).collect(),
+                timeout: Duration::from_millis(500),
+            })
+        }
+
+        fn post(state: &FakeState, url: &str, input: &str) -> (u16, String) {
+            respond(&state.0, &Method::Post, url, &mut input.as_bytes())
+        }
+
+        #[test]
+        fn test_days() {
+            let state = fake_state("days", &[(1, ""), (3, "")]);
+            assert_eq!(
+                respond(&state.0, &Method::Get, "/days", &mut "".as_bytes()),
+                (200, "{\"year\":2022,\"days\":[1,3]}".to_string())
+            );
+        }
+
+        #[test]
+        fn test_solve() {
+            // echoes the line count of the input as the answer of part one.
+            let script = format!(
+                "printf '🎄 Part 1{r} 🎄\\n%s (elapsed: 1.50ms)\\n🎄 Part 2{r} 🎄\\nnot solved.\\n' \"$(wc -l < \"${}\")\"",
+                INPUT_ENV,
+                r = advent_of_code::ANSI_RESET
+            );
+            let state = fake_state("solve", &[(1, &script)]);
+
+            assert_eq!(
+                post(&state, "/days/1/parts/1", "1\n2\n3\n"),
+                (
+                    200,
+                    "{\"day\":1,\"part\":1,\"answer\":\"3\",\"time_ms\":1.500}".to_string()
+                )
+            );
+            assert_eq!(post(&state, "/days/1/parts/2", "1\n").0, 404);
+            assert_eq!(post(&state, "/days/2/parts/1", "1\n").0, 404);
+        }
+
+        #[test]
+        fn test_solve_runs_requested_part() {
+            // part one takes longer than the timeout, so it must not run for part two.
+            let script = format!(
+                "[ \"${p}\" = 1 ] && sleep 5; [ \"${p}\" = 2 ] && printf '🎄 Part 2{r} 🎄\\n7 (elapsed: 1.00ms)\\n'",
+                p = PART_ENV,
+                r = advent_of_code::ANSI_RESET
+            );
+            let state = fake_state("part", &[(1, &script)]);
+
+            assert_eq!(post(&state, "/days/1/parts/2", "").0, 200);
+            assert_eq!(post(&state, "/days/1/parts/1", "").0, 504);
+        }
+
+        #[test]
+        fn test_solution_failures() {
+            let state = fake_state(
+                "failures",
+                &[(1, "echo 'bad input' >&2; exit 101"), (2, "sleep 5")],
+            );
+
+            let (status, body) = post(&state, "/days/1/parts/1", "");
+            assert_eq!(status, 500);
+            assert!(body.contains("bad input"));
+
+            let start = Instant::now();
+            assert_eq!(post(&state, "/days/2/parts/1", "").0, 504);
+            assert!(start.elapsed() < Duration::from_secs(5));
+        }
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use advent_of_code::debugger::{DEBUG_ENV, NO_SIMULATION_EXIT_CODE};
+use advent_of_code::INPUT_ENV;
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+
+pub const HELP: &str = "\
+Run the solution for a day.
+
+Usage: cargo solve <day> [options]
+
+Options:
+      --release              run an optimized build
+      --repl                 read inputs from the terminal and solve them in a loop
+      --debug <part>         step through the simulation of a part, if the day has one
+  -h, --help                 print this help
+";
+
+pub fn solve_command(day: u8, release: bool) -> Result<Command, Error> {
+    let day_padded = format!("{:02}", day);
+    if !Path::new("src/bin")
+        .join(format!("{}.rs", day_padded))
+        .exists()
+    {
+        return Err(Error::Failed(format!(
+            "day {} has no solution yet. Try running `cargo scaffold {}` first.",
+            day, day
+        )));
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg("run");
+    if release {
+        cmd.arg("--release");
+    }
+    cmd.args(["--bin", &day_padded]);
+    Ok(cmd)
+}
+
+/// runs the optimized solution for `day` and captures its output.
+pub fn run_day(day: u8) -> Result<Output, Error> {
+    solve_command(day, true)?
+        .output()
+        .map_err(|e| Error::Failed(format!("failed to run solution: {}", e)))
+}
+
+/// line that ends an input in the REPL, besides EOF.
+const REPL_SENTINEL: &str = ".";
+
+/// reads lines until EOF or the sentinel line. `None` if EOF was reached before reading anything.
+fn read_snippet(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut snippet = String::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok((!snippet.is_empty()).then_some(snippet));
+        }
+        if line.trim_end_matches(['\r', '\n']) == REPL_SENTINEL {
+            return Ok(Some(snippet));
+        }
+        snippet.push_str(&line);
+    }
+}
+
+/// a temp file holding the current REPL input, removed when dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        #[allow(unused_must_use)]
+        {
+            fs::remove_file(&self.path);
+        }
+    }
+}
+
+fn repl(day: u8, release: bool) -> Result<(), Error> {
+    // fail early if the day does not exist.
+    solve_command(day, release)?;
+
+    let input = TempFile {
+        path: env::temp_dir().join(format!("aoc_repl_{}.txt", std::process::id())),
+    };
+    let mut stdin = io::stdin().lock();
+
+    loop {
+        println!(
+            "Paste an input for day {}, end it with a line \"{}\" or Ctrl-D. Ctrl-D on an empty input quits.",
+            day, REPL_SENTINEL
+        );
+        io::stdout().flush().ok();
+
+        let snippet = match read_snippet(&mut stdin) {
+            Ok(Some(snippet)) if snippet.trim().is_empty() => continue,
+            Ok(Some(snippet)) => snippet,
+            Ok(None) => break,
+            Err(e) => return Err(Error::Failed(format!("could not read input: {}", e))),
+        };
+
+        fs::write(&input.path, snippet)
+            .map_err(|e| Error::Failed(format!("could not write input: {}", e)))?;
+
+        // a panicking solution only ends this run, not the REPL.
+        let status = solve_command(day, release)?
+            .arg("--quiet")
+            .env(INPUT_ENV, &input.path)
+            .status()
+            .map_err(|e| Error::Failed(format!("failed to run solution: {}", e)))?;
+        if !status.success() {
+            eprintln!("solution for day {} exited with {}", day, status);
+        }
+        println!("---");
+    }
+
+    Ok(())
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let release = args.contains("--release");
+    let interactive = args.contains("--repl");
+    let debug: Option<u8> = args.opt_value_from_fn("--debug", args::parse_part)?;
+    let day = args::day(&mut args)?;
+    args::finish(args)?;
+
+    if interactive {
+        return repl(day, release);
+    }
+
+    let mut cmd = solve_command(day, release)?;
+    if let Some(part) = debug {
+        cmd.env(DEBUG_ENV, part.to_string());
+    }
+    let status = cmd
+        .status()
+        .map_err(|e: io::Error| Error::Failed(format!("failed to run solution: {}", e)))?;
+
+    match status.code() {
+        Some(0) => Ok(()),
+        // solutions report it themselves, as only they know whether they start the debugger.
+        Some(NO_SIMULATION_EXIT_CODE) if debug.is_some() => Err(Error::Failed(format!(
+            "day {} has no simulation to debug. Implement `advent_of_code::debugger::Simulation` first.",
+            day
+        ))),
+        _ => Err(Error::Failed(format!(
+            "solution for day {} exited with {}",
+            day, status
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_snippet() {
+        let mut reader = "[1,1,3]\n[1,1,5]\n.\n\n[[1]]\n.\n[2]\n".as_bytes();
+        assert_eq!(
+            read_snippet(&mut reader).unwrap(),
+            Some("[1,1,3]\n[1,1,5]\n".to_string())
+        );
+        assert_eq!(
+            read_snippet(&mut reader).unwrap(),
+            Some("\n[[1]]\n".to_string())
+        );
+        assert_eq!(
+            read_snippet(&mut reader).unwrap(),
+            Some("[2]\n".to_string())
+        );
+        assert_eq!(read_snippet(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn test_temp_file_is_removed() {
+        let path = env::temp_dir().join(format!("aoc_repl_test_{}.txt", std::process::id()));
+        fs::write(&path, "1\n").unwrap();
+        drop(TempFile { path: path.clone() });
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,232 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+
+use advent_of_code::submissions::{has_star, read_submissions, Submission};
+use advent_of_code::{DAYS, YEAR};
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::scaffold::{README_PATH, README_TABLE_MARKER};
+
+pub const HELP: &str = "\
+Regenerate the stars table in the readme from the submission log. Stars already in
+the table are kept.
+
+Usage: cargo stars [options]
+
+Options:
+  -h, --help                 print this help
+";
+
+/// Stars of a day, as recorded in the submission log.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayStars {
+    pub day: u8,
+    pub stars: [bool; 2],
+}
+
+/// reads the stars from the table between the markers in `readme`.
+pub fn table_stars(readme: &str) -> Vec<DayStars> {
+    readme
+        .split(README_TABLE_MARKER)
+        .nth(1)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|l| {
+            let day = l.strip_prefix("| [Day ")?.split(']').next()?.parse().ok()?;
+            let cells: Vec<&str> = l.split('|').collect();
+            let star = |i: usize| cells.get(i).is_some_and(|c| c.contains('⭐'));
+            Some(DayStars {
+                day,
+                stars: [star(2), star(3)],
+            })
+        })
+        .collect()
+}
+
+/// lists the scaffolded days and all days with a star, sorted by day. a part has a star if the
+/// submission log or the `recorded` stars have one.
+pub fn day_stars(days: &[u8], submissions: &[Submission], recorded: &[DayStars]) -> Vec<DayStars> {
+    let mut listed: Vec<u8> = submissions
+        .iter()
+        .filter(|s| has_star(submissions, YEAR, s.day, s.part))
+        .map(|s| s.day)
+        .chain(recorded.iter().map(|d| d.day))
+        .chain(days.iter().copied())
+        .collect();
+    listed.sort_unstable();
+    listed.dedup();
+
+    let recorded_star =
+        |day: u8, part: usize| recorded.iter().any(|d| d.day == day && d.stars[part - 1]);
+
+    listed
+        .into_iter()
+        .map(|day| DayStars {
+            day,
+            stars: [1, 2].map(|part| {
+                has_star(submissions, YEAR, day, part) || recorded_star(day, part as usize)
+            }),
+        })
+        .collect()
+}
+
+/// the stars of all days, from the submission log and the readme table.
+pub fn read_stars() -> Vec<DayStars> {
+    let readme = fs::read_to_string(README_PATH).unwrap_or_default();
+    day_stars(DAYS, &read_submissions(), &table_stars(&readme))
+}
+
+pub fn count_stars(days: &[DayStars]) -> usize {
+    days.iter()
+        .map(|d| d.stars.iter().filter(|s| **s).count())
+        .sum()
+}
+
+fn stars_table(year: i16, days: &[DayStars]) -> String {
+    let star = |earned: bool| if earned { " ⭐ |" } else { " |" };
+
+    let mut table = format!(
+        "{}\n## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        README_TABLE_MARKER, year
+    );
+    for d in days {
+        table.push_str(&format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) |{}{}\n",
+            d.day,
+            year,
+            d.day,
+            star(d.stars[0]),
+            star(d.stars[1])
+        ));
+    }
+    table.push_str(README_TABLE_MARKER);
+    table
+}
+
+/// replaces the table between the markers, including the markers. `None` if there is no table.
+fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(README_TABLE_MARKER)?;
+    let after_start = start + README_TABLE_MARKER.len();
+    let end = after_start + readme[after_start..].find(README_TABLE_MARKER)?;
+
+    Some(format!(
+        "{}{}{}",
+        &readme[..start],
+        table,
+        &readme[end + README_TABLE_MARKER.len()..]
+    ))
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    args::finish(args)?;
+
+    let readme = fs::read_to_string(README_PATH)
+        .map_err(|e| Error::Failed(format!("could not read \"{}\": {}", README_PATH, e)))?;
+    let days = day_stars(DAYS, &read_submissions(), &table_stars(&readme));
+    let table = stars_table(YEAR, &days);
+
+    let updated = replace_table(&readme, &table).ok_or_else(|| {
+        Error::Failed(format!(
+            "could not find the stars table in \"{}\". Add two lines \"{}\" where it should go.",
+            README_PATH, README_TABLE_MARKER
+        ))
+    })?;
+
+    if updated == readme {
+        println!("Stars table in \"{}\" is up to date.", README_PATH);
+    } else {
+        fs::write(README_PATH, updated)
+            .map_err(|e| Error::Failed(format!("could not write \"{}\": {}", README_PATH, e)))?;
+        println!("Updated stars table in \"{}\".", README_PATH);
+    }
+    println!("---");
+    println!("🎄 {} stars collected.", count_stars(&days));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::submissions::parse_submissions;
+
+    #[test]
+    fn test_day_stars() {
+        let submissions = parse_submissions(
+            "1 2022 1 1 right 24000\n2 2022 1 2 wrong 1\n3 2022 20 1 right 5\n4 2022 21 1 too_low 3\n5 2021 22 1 right 7\n6 2022 23 1 already_completed 9\n",
+        );
+        let recorded = [DayStars {
+            day: 2,
+            stars: [true, true],
+        }];
+        assert_eq!(
+            day_stars(&[1, 2], &submissions, &recorded),
+            vec![
+                DayStars {
+                    day: 1,
+                    stars: [true, false]
+                },
+                DayStars {
+                    day: 2,
+                    stars: [true, true]
+                },
+                DayStars {
+                    day: 20,
+                    stars: [true, false]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stars_table() {
+        let days = [
+            DayStars {
+                day: 1,
+                stars: [true, true],
+            },
+            DayStars {
+                day: 12,
+                stars: [true, false],
+            },
+        ];
+        let table = stars_table(2022, &days);
+        assert!(table.contains(
+            "| :---: | :---: | :---: |\n| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n"
+        ));
+        assert!(table.contains("| [Day 12](https://adventofcode.com/2022/day/12) | ⭐ | |\n"));
+
+        let readme = format!("# AoC\n\n{m}\nold\n{m}\n\n---\n", m = README_TABLE_MARKER);
+        let updated = replace_table(&readme, &table).unwrap();
+        assert_eq!(updated, format!("# AoC\n\n{}\n\n---\n", table));
+        assert_eq!(replace_table(&updated, &table), Some(updated));
+        assert_eq!(replace_table("# AoC\n", &table), None);
+    }
+
+    #[test]
+    fn test_table_stars() {
+        let days = [
+            DayStars {
+                day: 1,
+                stars: [true, true],
+            },
+            DayStars {
+                day: 12,
+                stars: [true, false],
+            },
+            DayStars {
+                day: 13,
+                stars: [false, false],
+            },
+        ];
+        let readme = format!("# AoC\n\n{}\n\n---\n", stars_table(2022, &days));
+        assert_eq!(table_stars(&readme), days);
+        assert_eq!(table_stars("# AoC\n"), vec![]);
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::stars::{count_stars, read_stars, DayStars};
+
+pub const HELP: &str = "\
+Print the stars collected per day, as recorded in the submission log and the readme.
+
+Usage: cargo status [options]
+
+Options:
+  -h, --help                 print this help
+";
+
+fn status_line(d: &DayStars) -> String {
+    // stars are two columns wide, so pad them by hand.
+    let star = |earned: bool| if earned { "⭐" } else { "  " };
+    format!(
+        "Day {:<4}{}      {}",
+        d.day,
+        star(d.stars[0]),
+        star(d.stars[1])
+    )
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    args::finish(args)?;
+
+    let days = read_stars();
+
+    println!("Day     Part 1  Part 2");
+    for d in &days {
+        println!("{}", status_line(d));
+    }
+    println!("---");
+    println!("🎄 {} stars collected.", count_stars(&days));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_line() {
+        let d = DayStars {
+            day: 12,
+            stars: [true, false],
+        };
+        assert_eq!(status_line(&d), "Day 12  ⭐        ");
+    }
+}
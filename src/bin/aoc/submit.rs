@@ -0,0 +1,303 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::{self, BufRead, Write};
+use std::process::Command;
+
+use advent_of_code::submissions::{
+    find_known_wrong, read_submissions, record_submission, Outcome, Submission,
+};
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::cache::{self, Cache, Throttle};
+use crate::solve::run_day;
+
+pub const HELP: &str = "\
+Solve a part and submit the answer with aoc-cli.
+
+Usage: cargo submit <day> <part> [options]
+
+Options:
+  -y, --year <year>          year of the puzzle
+      --yes                  submit without asking for confirmation
+  -h, --help                 print this help
+";
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<i16>,
+    yes: bool,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, Error> {
+    let year = args::year(&mut args)?;
+    let yes = args.contains("--yes");
+    let day = args::day(&mut args)?;
+    let part = match args.free_from_fn(args::parse_part) {
+        Err(pico_args::Error::MissingArgument) => {
+            return Err(Error::Usage("need to specify a part (1 or 2)".into()));
+        }
+        part => part?,
+    };
+    args::finish(args)?;
+    Ok(Args {
+        day,
+        part,
+        year,
+        yes,
+    })
+}
+
+/// runs the solution for `day` and returns the answer it printed for `part`.
+fn solve(day: u8, part: u8) -> Result<String, Error> {
+    let output = run_day(day)?;
+
+    if !output.status.success() {
+        io::stderr().write_all(&output.stderr).ok();
+        return Err(Error::Failed(format!("solution for day {} failed", day)));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    advent_of_code::parse_answer(&stdout, part)
+        .ok_or_else(|| Error::Failed(format!("day {} part {} is not solved", day, part)))
+}
+
+fn confirm(prompt: &str) -> bool {
+    print!("{} [y/N] ", prompt);
+    io::stdout().flush().ok();
+
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        Ok(_) => matches!(line.trim(), "y" | "Y" | "yes"),
+        Err(_) => false,
+    }
+}
+
+fn year(args: &Args) -> i16 {
+    args.year.unwrap_or(advent_of_code::YEAR)
+}
+
+fn submission_key(args: &Args, answer: &str) -> String {
+    cache::submission_key(year(args), args.day, args.part, answer)
+}
+
+/// submits `answer` with aoc-cli. `aoc` is resolved via `PATH`, so it can be swapped for a fake in tests.
+/// responses that judge the answer are cached, so the same answer is never submitted twice.
+/// `already_completed` is not cached, as the website answers the same for a part that is locked.
+fn submit(mut aoc: Command, cache: &Cache, args: &Args, answer: &str) -> Result<Outcome, String> {
+    aoc.args([
+        "--year",
+        &year(args).to_string(),
+        "--day",
+        &args.day.to_string(),
+        "submit",
+        &args.part.to_string(),
+        answer,
+    ]);
+
+    let output = aoc
+        .output()
+        .map_err(|e| format!("failed to spawn aoc-cli: {}", e))?;
+
+    io::stdout()
+        .write_all(&output.stdout)
+        .expect("could not write cmd stdout to pipe.");
+    io::stderr()
+        .write_all(&output.stderr)
+        .expect("could not write cmd stderr to pipe.");
+
+    if !output.status.success() {
+        return Err(format!("aoc-cli exited with {}", output.status));
+    }
+
+    let response = String::from_utf8_lossy(&output.stdout);
+    let outcome = Outcome::from_response(&response);
+
+    if matches!(
+        outcome,
+        Outcome::Right | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+    ) {
+        if let Err(e) = cache.put(&submission_key(args, answer), &response) {
+            eprintln!("could not cache response: {}", e);
+        }
+    }
+
+    Ok(outcome)
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let args = parse_args(args)?;
+
+    // check if aoc binary exists and is callable.
+    if Command::new("aoc").arg("-V").output().is_err() {
+        return Err(Error::Failed(
+            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+                .into(),
+        ));
+    }
+
+    println!("Solving day {} part {}...", args.day, args.part);
+
+    let answer = solve(args.day, args.part)?;
+
+    println!("Answer: {}", answer);
+
+    let year = year(&args);
+    if let Some(reason) = find_known_wrong(&read_submissions(), year, args.day, args.part, &answer)
+    {
+        return Err(Error::Failed(format!("Not submitted: {}.", reason)));
+    }
+
+    let cache = Cache::new();
+    let cached_response = cache.get(&submission_key(&args, &answer));
+
+    if cached_response.is_none() && !args.yes && !confirm("Submit this answer?") {
+        println!("Not submitted.");
+        return Ok(());
+    }
+
+    let outcome = match cached_response {
+        Some(response) => {
+            println!("Already submitted, using cached response:");
+            print!("{}", response);
+            Outcome::from_response(&response)
+        }
+        None => {
+            if let Err(e) = Throttle::new().wait() {
+                eprintln!("could not record request time: {}", e);
+            }
+
+            let outcome = submit(Command::new("aoc"), &cache, &args, &answer)
+                .map_err(|e| Error::Failed(format!("Failed to submit answer: {}", e)))?;
+
+            if let Err(e) = record_submission(&Submission::new(
+                year, args.day, args.part, outcome, &answer,
+            )) {
+                eprintln!("could not record submission: {}", e);
+            }
+            outcome
+        }
+    };
+
+    println!("---");
+    let failure = match outcome {
+        Outcome::Right => {
+            println!("🎄 That's the right answer!");
+            return Ok(());
+        }
+        Outcome::AlreadyCompleted => {
+            println!("🎄 This part is already completed, or not unlocked yet.");
+            return Ok(());
+        }
+        Outcome::TooHigh => "❌ Wrong answer: too high.",
+        Outcome::TooLow => "❌ Wrong answer: too low.",
+        Outcome::Wrong => "❌ Wrong answer.",
+        Outcome::RateLimited => "⏳ Rate limited, try again later.",
+        Outcome::Unknown => "❓ Could not understand the response.",
+    };
+    Err(Error::Failed(failure.into()))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::{env, fs, os::unix::fs::PermissionsExt};
+
+    /// creates a fake `aoc` executable that echoes its arguments and prints `response`.
+    fn fake_aoc(name: &str, response: &str) -> Command {
+        let dir = env::temp_dir().join(format!("aoc_fake_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("aoc");
+        fs::write(
+            &path,
+            format!("#!/bin/sh\necho \"args: $*\"\necho \"{}\"\n", response),
+        )
+        .unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut cmd = Command::new("aoc");
+        cmd.env("PATH", dir);
+        cmd
+    }
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = env::temp_dir().join(format!("aoc_submit_cache_{}_{}", name, std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        Cache::at(dir)
+    }
+
+    fn args() -> Args {
+        Args {
+            day: 7,
+            part: 2,
+            year: Some(2022),
+            yes: true,
+        }
+    }
+
+    #[test]
+    fn test_submit_right() {
+        let aoc = fake_aoc(
+            "right",
+            "That's the right answer! You are one gold star closer.",
+        );
+        let cache = temp_cache("right");
+        assert_eq!(submit(aoc, &cache, &args(), "24933642"), Ok(Outcome::Right));
+        assert!(cache
+            .get(&submission_key(&args(), "24933642"))
+            .is_some_and(|r| r.contains("right answer")));
+    }
+
+    #[test]
+    fn test_submit_too_low() {
+        let aoc = fake_aoc(
+            "low",
+            "That's not the right answer; your answer is too low.",
+        );
+        let cache = temp_cache("low");
+        assert_eq!(submit(aoc, &cache, &args(), "1"), Ok(Outcome::TooLow));
+        assert!(cache.get(&submission_key(&args(), "1")).is_some());
+    }
+
+    #[test]
+    fn test_submit_zero() {
+        let aoc = fake_aoc("zero", "That's the right answer!");
+        let cache = temp_cache("zero");
+        assert_eq!(submit(aoc, &cache, &args(), "0"), Ok(Outcome::Right));
+        assert!(cache
+            .get(&submission_key(&args(), "0"))
+            .is_some_and(|r| r.contains("args: --year 2022 --day 7 submit 2 0")));
+    }
+
+    #[test]
+    fn test_submit_already_completed() {
+        let aoc = fake_aoc(
+            "completed",
+            "You don't seem to be solving the right level. Did you already complete it?",
+        );
+        let cache = temp_cache("completed");
+        assert_eq!(
+            submit(aoc, &cache, &args(), "1"),
+            Ok(Outcome::AlreadyCompleted)
+        );
+        assert_eq!(cache.get(&submission_key(&args(), "1")), None);
+
+        let aoc = fake_aoc("completed_again", "That's the right answer!");
+        assert_eq!(submit(aoc, &cache, &args(), "1"), Ok(Outcome::Right));
+        assert!(cache.get(&submission_key(&args(), "1")).is_some());
+    }
+
+    #[test]
+    fn test_submit_rate_limited() {
+        let aoc = fake_aoc("limited", "You gave an answer too recently.");
+        let cache = temp_cache("limited");
+        assert_eq!(submit(aoc, &cache, &args(), "1"), Ok(Outcome::RateLimited));
+        assert_eq!(cache.get(&submission_key(&args(), "1")), None);
+    }
+}
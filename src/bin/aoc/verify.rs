@@ -0,0 +1,315 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use advent_of_code::{crypto, parse_answer, INPUT_ENV};
+use pico_args::Arguments;
+
+use crate::args::{self, Error};
+use crate::solve::solve_command;
+
+pub const HELP: &str = "\
+Run the solution for a day against every input in src/inputs/<day>/.
+
+Usage: cargo verify <day>
+
+Inputs are the *.txt files in the directory (or their encrypted *.txt.enc
+files). Expected answers are listed in src/inputs/<day>/answers.txt, one line
+per input: `<name> <part one> <part two>`, with `-` for an unknown answer.
+
+Options:
+  -h, --help                 print this help
+";
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Input {
+    /// file name without the `.txt` extension.
+    name: String,
+    path: PathBuf,
+    encrypted: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Check {
+    Passed(String),
+    Failed {
+        answer: Option<String>,
+        expected: String,
+    },
+    Unknown(Option<String>),
+}
+
+impl Check {
+    fn new(answer: Option<String>, expected: Option<&String>) -> Self {
+        match (answer, expected) {
+            (Some(answer), Some(expected)) if answer == *expected => Check::Passed(answer),
+            (answer, Some(expected)) => Check::Failed {
+                answer,
+                expected: expected.clone(),
+            },
+            (answer, None) => Check::Unknown(answer),
+        }
+    }
+}
+
+/// lists the inputs in `dir`, sorted by name. encrypted inputs are only listed without a plaintext copy.
+fn find_inputs(dir: &Path) -> Vec<Input> {
+    let mut inputs: Vec<Input> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let file_name = path.file_name()?.to_str()?.to_string();
+            if file_name == ANSWERS_FILE {
+                return None;
+            }
+            if let Some(name) = file_name.strip_suffix(".txt") {
+                return Some(Input {
+                    name: name.to_string(),
+                    path,
+                    encrypted: false,
+                });
+            }
+            let name = file_name.strip_suffix(&format!(".txt.{}", crypto::ENCRYPTED_EXTENSION))?;
+            match path.with_extension("").exists() {
+                true => None,
+                false => Some(Input {
+                    name: name.to_string(),
+                    path,
+                    encrypted: true,
+                }),
+            }
+        })
+        .collect();
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    inputs
+}
+
+fn parse_input_answers(contents: &str) -> HashMap<String, [Option<String>; 2]> {
+    contents
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let mut parts = l.split_whitespace();
+            let name = parts.next()?.trim_end_matches(".txt").to_string();
+            let mut answer = || {
+                parts
+                    .next()
+                    .filter(|val| *val != "-")
+                    .map(|val| val.to_string())
+            };
+            Some((name, [answer(), answer()]))
+        })
+        .collect()
+}
+
+/// returns the path of the plaintext input, decrypting it to `scratch` if needed.
+fn plaintext_path(
+    input: &Input,
+    key: &mut Option<crypto::Key>,
+    scratch: &Path,
+) -> Result<PathBuf, String> {
+    if !input.encrypted {
+        return Ok(input.path.clone());
+    }
+    let key = match key {
+        Some(key) => key,
+        None => key.insert(crypto::read_key()?),
+    };
+    let ciphertext = fs::read_to_string(&input.path)
+        .map_err(|e| format!("could not read \"{}\": {}", input.path.display(), e))?;
+    let plaintext = crypto::decrypt(key, &format!("{}.txt", input.name), &ciphertext)?;
+    fs::write(scratch, plaintext).map_err(|e| format!("could not write input: {}", e))?;
+    Ok(scratch.to_path_buf())
+}
+
+/// runs the solution on one input and checks both parts against the expected answers.
+fn verify_input(
+    day: u8,
+    path: &Path,
+    expected: &[Option<String>; 2],
+) -> Result<[Check; 2], String> {
+    let output = solve_command(day, true)
+        .map_err(|_| format!("day {} has no solution", day))?
+        .arg("--quiet")
+        .env(INPUT_ENV, path)
+        .output()
+        .map_err(|e| format!("failed to run solution: {}", e))?;
+
+    if !output.status.success() {
+        // report the panic message. since rust 1.73, it follows on the line after "panicked at".
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let lines: Vec<&str> = stderr.lines().collect();
+        let message = lines
+            .iter()
+            .position(|l| l.contains("panicked at"))
+            .map(|i| match lines[i].ends_with(':') {
+                true => lines.get(i + 1).unwrap_or(&lines[i]),
+                false => lines[i],
+            });
+        return Err(match message {
+            Some(line) => line.trim().to_string(),
+            None => format!("solution exited with {}", output.status),
+        });
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok([0, 1].map(|i| Check::new(parse_answer(&stdout, i as u8 + 1), expected[i].as_ref())))
+}
+
+fn describe(check: &Check) -> String {
+    let answer = |a: &Option<String>| a.clone().unwrap_or_else(|| "not solved".into());
+    match check {
+        Check::Passed(answer) => format!("✅ {}", answer),
+        Check::Failed {
+            answer: a,
+            expected,
+        } => format!("❌ {} (expected {})", answer(a), expected),
+        Check::Unknown(a) => format!("❔ {} (no expected answer)", answer(a)),
+    }
+}
+
+pub fn run(mut args: Arguments) -> Result<(), Error> {
+    if args::help(&mut args, HELP) {
+        return Ok(());
+    }
+    let day = args::day(&mut args)?;
+    args::finish(args)?;
+    // fail early if the day does not exist.
+    solve_command(day, true)?;
+
+    let dir = Path::new("src/inputs").join(format!("{:02}", day));
+    let inputs = find_inputs(&dir);
+    if inputs.is_empty() {
+        return Err(Error::Failed(format!(
+            "no inputs found in \"{}\". Add one `<name>.txt` file per input.",
+            dir.display()
+        )));
+    }
+
+    let answers = fs::read_to_string(dir.join(ANSWERS_FILE))
+        .map(|contents| parse_input_answers(&contents))
+        .unwrap_or_default();
+    let scratch = env::temp_dir().join(format!("aoc_verify_{}.txt", std::process::id()));
+    let mut key = None;
+    let mut failed = 0;
+    let mut unverified = 0;
+
+    for input in &inputs {
+        let expected = answers.get(&input.name).cloned().unwrap_or_default();
+        let result = plaintext_path(input, &mut key, &scratch)
+            .and_then(|path| verify_input(day, &path, &expected));
+
+        match result {
+            Ok(checks) => {
+                println!(
+                    "{}: part 1 {}, part 2 {}",
+                    input.name,
+                    describe(&checks[0]),
+                    describe(&checks[1])
+                );
+                if checks.iter().any(|c| matches!(c, Check::Failed { .. })) {
+                    failed += 1;
+                } else if checks.iter().any(|c| matches!(c, Check::Unknown(_))) {
+                    unverified += 1;
+                }
+            }
+            Err(e) => {
+                println!("{}: ❌ {}", input.name, e);
+                failed += 1;
+            }
+        }
+    }
+    fs::remove_file(&scratch).ok();
+
+    println!("---");
+    println!(
+        "🎄 {} of {} inputs pass, {} without all expected answers.",
+        inputs.len() - failed - unverified,
+        inputs.len(),
+        unverified
+    );
+    match failed {
+        0 => Ok(()),
+        _ => Err(Error::Failed(format!("{} inputs failed.", failed))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_inputs() {
+        let dir = env::temp_dir().join(format!("aoc_verify_inputs_{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        for file in [
+            "bob.txt",
+            "alice.txt.enc",
+            "bob.txt.enc",
+            ANSWERS_FILE,
+            "notes.md",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let inputs = find_inputs(&dir);
+        assert_eq!(
+            inputs,
+            vec![
+                Input {
+                    name: "alice".into(),
+                    path: dir.join("alice.txt.enc"),
+                    encrypted: true
+                },
+                Input {
+                    name: "bob".into(),
+                    path: dir.join("bob.txt"),
+                    encrypted: false
+                },
+            ]
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_parse_input_answers() {
+        let answers =
+            parse_input_answers("# name part_one part_two\nalice 24000 45000\nbob.txt 31 -\n");
+        assert_eq!(
+            answers.get("alice"),
+            Some(&[Some("24000".to_string()), Some("45000".to_string())])
+        );
+        assert_eq!(answers.get("bob"), Some(&[Some("31".to_string()), None]));
+        assert_eq!(answers.get("carol"), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = "31".to_string();
+        assert_eq!(
+            Check::new(Some("31".into()), Some(&expected)),
+            Check::Passed("31".into())
+        );
+        assert_eq!(
+            Check::new(None, Some(&expected)),
+            Check::Failed {
+                answer: None,
+                expected: "31".into()
+            }
+        );
+        assert_eq!(
+            Check::new(Some("29".into()), None),
+            Check::Unknown(Some("29".into()))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+use pico_args::Arguments;
+
+use args::Error;
+
+// `cargo run --bin serve` runs the same code as `cargo aoc serve`.
+#[allow(dead_code)]
+#[path = "aoc/args.rs"]
+mod args;
+#[path = "aoc/serve.rs"]
+mod serve;
+
+fn main() {
+    match serve::run(Arguments::from_env()) {
+        Ok(()) => {}
+        Err(Error::Usage(e)) => {
+            eprintln!("error: {}", e);
+            eprintln!("Run `cargo serve --help` for usage.");
+            process::exit(2);
+        }
+        Err(Error::Failed(e)) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
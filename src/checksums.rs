@@ -0,0 +1,110 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use sha2::{Digest, Sha256};
+
+fn checksums_path() -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("src")
+        .join("checksums.txt")
+}
+
+/// returns the hex encoded SHA-256 checksum of an input.
+pub fn checksum(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// parses lines of `<day> <checksum>`.
+pub fn parse_checksums(contents: &str) -> HashMap<u8, String> {
+    contents
+        .lines()
+        .filter_map(|l| {
+            let (day, checksum) = l.trim().split_once(' ')?;
+            Some((day.parse().ok()?, checksum.trim().to_string()))
+        })
+        .collect()
+}
+
+/// sets the checksum of `day`, keeping the lines sorted by day.
+pub fn merge_checksum(contents: &str, day: u8, checksum: &str) -> String {
+    let mut checksums: Vec<(u8, String)> = parse_checksums(contents).into_iter().collect();
+    checksums.retain(|(d, _)| *d != day);
+    checksums.push((day, checksum.to_string()));
+    checksums.sort();
+
+    checksums
+        .iter()
+        .map(|(day, checksum)| format!("{:02} {}\n", day, checksum))
+        .collect()
+}
+
+/// returns the checksums recorded in `src/checksums.txt`.
+pub fn read_checksums() -> HashMap<u8, String> {
+    match fs::read_to_string(checksums_path()) {
+        Ok(contents) => parse_checksums(&contents),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// records the checksum of the input of `day`, replacing an earlier one.
+pub fn record_checksum(day: u8, contents: &str) -> io::Result<()> {
+    let path = checksums_path();
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    fs::write(path, merge_checksum(&existing, day, &checksum(contents)))
+}
+
+/// returns a warning if the input of `day` does not match its recorded checksum.
+/// inputs without a recorded checksum are not checked.
+pub fn verify(checksums: &HashMap<u8, String>, day: u8, contents: &str) -> Option<String> {
+    let expected = checksums.get(&day)?;
+    match *expected == checksum(contents) {
+        true => None,
+        false => Some(format!(
+            "input for day {:02} changed since it was downloaded. If this is intended, run `cargo accept-input {}`.",
+            day, day
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(
+            checksum(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_merge_checksum() {
+        let contents = "01 aaa\n03 ccc\n";
+        assert_eq!(
+            merge_checksum(contents, 2, "bbb"),
+            "01 aaa\n02 bbb\n03 ccc\n"
+        );
+        assert_eq!(merge_checksum(contents, 3, "ddd"), "01 aaa\n03 ddd\n");
+        assert_eq!(merge_checksum("", 12, "eee"), "12 eee\n");
+    }
+
+    #[test]
+    fn test_verify() {
+        let checksums = parse_checksums(&format!("01 {}\n", checksum("1000\n")));
+        assert_eq!(verify(&checksums, 1, "1000\n"), None);
+        assert!(verify(&checksums, 1, "1000").is_some());
+        assert_eq!(verify(&checksums, 2, "anything"), None);
+    }
+}
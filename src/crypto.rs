@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aes_siv::siv::Aes256Siv;
+use aes_siv::KeyInit;
+
+pub type Key = [u8; 64];
+
+/// extension of encrypted input files, e.g. `src/inputs/01.txt.enc`.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// path of the key file: `AOC_KEY_FILE` if set, `.aoc_key` in the project root otherwise.
+pub fn key_path() -> PathBuf {
+    match env::var_os("AOC_KEY_FILE") {
+        Some(path) => PathBuf::from(path),
+        None => env::current_dir().unwrap().join(".aoc_key"),
+    }
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(ENCRYPTED_EXTENSION);
+    path.with_file_name(name)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = hex.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err("hex string has an odd length".into());
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .ok_or_else(|| "invalid hex digit".to_string())
+        })
+        .collect()
+}
+
+/// generates a new random key, hex encoded.
+pub fn generate_key() -> Result<String, String> {
+    let mut key: Key = [0; 64];
+    getrandom::getrandom(&mut key).map_err(|e| format!("could not generate key: {}", e))?;
+    Ok(to_hex(&key))
+}
+
+pub fn parse_key(contents: &str) -> Result<Key, String> {
+    from_hex(contents)?
+        .try_into()
+        .map_err(|_| "key must be 64 bytes (128 hex digits)".to_string())
+}
+
+pub fn read_key() -> Result<Key, String> {
+    let path = key_path();
+    let contents = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "could not read key file \"{}\": {}. Ask a teammate for the key or set AOC_KEY_FILE.",
+            path.display(),
+            e
+        )
+    })?;
+    parse_key(&contents).map_err(|e| format!("invalid key file \"{}\": {}", path.display(), e))
+}
+
+/// encrypts `plaintext` with AES-SIV. the output only depends on the key, `name` and the
+/// plaintext, so unchanged inputs encrypt to identical files. `name` is authenticated, which
+/// prevents swapping the ciphertexts of two files.
+pub fn encrypt(key: &Key, name: &str, plaintext: &str) -> String {
+    let mut cipher = Aes256Siv::new(key.into());
+    let ciphertext = cipher
+        .encrypt([name.as_bytes()], plaintext.as_bytes())
+        .expect("plaintext too large to encrypt");
+
+    let mut result = String::new();
+    for line in to_hex(&ciphertext).as_bytes().chunks(64) {
+        result.push_str(std::str::from_utf8(line).unwrap());
+        result.push('\n');
+    }
+    result
+}
+
+pub fn decrypt(key: &Key, name: &str, ciphertext: &str) -> Result<String, String> {
+    let mut cipher = Aes256Siv::new(key.into());
+    let plaintext = cipher
+        .decrypt([name.as_bytes()], &from_hex(ciphertext)?)
+        .map_err(|_| format!("could not decrypt \"{}\", is the key correct?", name))?;
+    String::from_utf8(plaintext).map_err(|e| format!("decrypted \"{}\" is not utf-8: {}", name, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: Key = [7; 64];
+
+    #[test]
+    fn test_roundtrip() {
+        let ciphertext = encrypt(&KEY, "01.txt", "1000\n2000\n");
+        assert_eq!(
+            decrypt(&KEY, "01.txt", &ciphertext),
+            Ok("1000\n2000\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_eq!(
+            encrypt(&KEY, "01.txt", "1000\n"),
+            encrypt(&KEY, "01.txt", "1000\n")
+        );
+        assert_ne!(
+            encrypt(&KEY, "01.txt", "1000\n"),
+            encrypt(&KEY, "02.txt", "1000\n")
+        );
+    }
+
+    #[test]
+    fn test_decrypt_errors() {
+        let ciphertext = encrypt(&KEY, "01.txt", "1000\n");
+        assert!(decrypt(&[8; 64], "01.txt", &ciphertext).is_err());
+        assert!(decrypt(&KEY, "02.txt", &ciphertext).is_err());
+        assert!(decrypt(&KEY, "01.txt", "zz").is_err());
+    }
+
+    #[test]
+    fn test_parse_key() {
+        let key = generate_key().unwrap();
+        assert_eq!(key.len(), 128);
+        assert!(parse_key(&format!("{}\n", key)).is_ok());
+        assert!(parse_key("abcd").is_err());
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(
+            encrypted_path(Path::new("src/inputs/01.txt")),
+            PathBuf::from("src/inputs/01.txt.enc")
+        );
+    }
+}
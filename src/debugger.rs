@@ -0,0 +1,440 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+
+/// environment variable selecting the part to debug, set by `cargo solve <day> --debug <part>`.
+pub const DEBUG_ENV: &str = "AOC_DEBUG";
+
+/// A simulation that can be stepped through with the debugger.
+pub trait Simulation {
+    /// advances the simulation by one step. returns `false` if it had already finished.
+    fn step(&mut self) -> bool;
+
+    /// named values that conditions can refer to, e.g. `("x", 21)`.
+    fn values(&self) -> Vec<(String, i64)>;
+
+    /// a drawing of the current state, shown by `print`.
+    fn render(&self) -> String;
+}
+
+/// exit status of a solution started with `--debug` that has no simulation to debug.
+pub const NO_SIMULATION_EXIT_CODE: i32 = 3;
+
+/// ends a solution that was started with `--debug` but reached `solve!` without starting the
+/// debugger, so the runner can tell that the day has no simulation.
+pub fn no_simulation() -> ! {
+    eprintln!("this day has no simulation to debug.");
+    std::process::exit(NO_SIMULATION_EXIT_CODE)
+}
+
+/// the part to debug if the solution was started with `--debug`.
+pub fn requested_part() -> Option<u8> {
+    env::var(DEBUG_ENV).ok()?.trim().parse().ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A comparison such as `x >= 20` or `step % 40 == 0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Clause {
+    name: String,
+    modulo: Option<i64>,
+    comparison: Comparison,
+    value: i64,
+}
+
+/// Comparisons joined by `&&`. a plain number `N` is short for `step == N`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    source: String,
+    clauses: Vec<Clause>,
+}
+
+fn parse_clause(clause: &str) -> Result<Clause, String> {
+    let clause = clause.trim();
+    if let Ok(value) = clause.parse() {
+        return Ok(Clause {
+            name: "step".into(),
+            modulo: None,
+            comparison: Comparison::Eq,
+            value,
+        });
+    }
+
+    // two-character operators first, so `<=` is not read as `<`.
+    let operators = [
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+    let (left, comparison, right) = operators
+        .iter()
+        .find_map(|(op, comparison)| {
+            clause
+                .split_once(op)
+                .map(|(left, right)| (left, *comparison, right))
+        })
+        .ok_or_else(|| format!("\"{}\" has no comparison (==, !=, <, <=, >, >=)", clause))?;
+
+    let value = right
+        .trim()
+        .parse()
+        .map_err(|_| format!("\"{}\" is not a number", right.trim()))?;
+    let (name, modulo) = match left.split_once('%') {
+        Some((name, modulo)) => {
+            let modulo = modulo
+                .trim()
+                .parse()
+                .ok()
+                .filter(|m| *m != 0)
+                .ok_or_else(|| format!("\"{}\" is not a non-zero number", modulo.trim()))?;
+            (name, Some(modulo))
+        }
+        None => (left, None),
+    };
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(format!("\"{}\" is not a value name", name));
+    }
+
+    Ok(Clause {
+        name: name.to_string(),
+        modulo,
+        comparison,
+        value,
+    })
+}
+
+impl Condition {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let clauses = source
+            .split("&&")
+            .map(parse_clause)
+            .collect::<Result<_, _>>()?;
+        Ok(Condition {
+            source: source.trim().to_string(),
+            clauses,
+        })
+    }
+
+    /// evaluates the condition. fails if it refers to a value that does not exist.
+    pub fn eval(&self, values: &[(String, i64)]) -> Result<bool, String> {
+        for clause in &self.clauses {
+            let value = values
+                .iter()
+                .find(|(name, _)| *name == clause.name)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| {
+                    let names: Vec<&str> = values.iter().map(|(name, _)| name.as_str()).collect();
+                    format!(
+                        "unknown value \"{}\", use one of: {}",
+                        clause.name,
+                        names.join(", ")
+                    )
+                })?;
+            let value = match clause.modulo {
+                Some(modulo) => value.rem_euclid(modulo),
+                None => value,
+            };
+            let holds = match clause.comparison {
+                Comparison::Eq => value == clause.value,
+                Comparison::Ne => value != clause.value,
+                Comparison::Lt => value < clause.value,
+                Comparison::Le => value <= clause.value,
+                Comparison::Gt => value > clause.value,
+                Comparison::Ge => value >= clause.value,
+            };
+            if !holds {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+const HELP: &str = "\
+Commands:
+  s, step              run one step (same as an empty line)
+  n, next <count>      run <count> steps
+  c, continue          run until a breakpoint or the end
+  u, until <cond>      run until <cond> holds
+  b, break <cond>      stop whenever <cond> holds, e.g. `break 100` or `break step % 40 == 20`
+  d, delete            remove all breakpoints
+  p, print             print the state
+  h, help              print this help
+  q, quit              stop debugging
+Conditions compare values with ==, !=, <, <=, >, >=, optionally `value % n`, joined by &&.
+";
+
+/// Steps through a simulation, keeping track of the step count and breakpoints.
+pub struct Debugger<S: Simulation> {
+    simulation: S,
+    step: i64,
+    finished: bool,
+    breakpoints: Vec<Condition>,
+}
+
+enum Stop {
+    Steps(i64),
+    Condition(Condition),
+    End,
+}
+
+impl<S: Simulation> Debugger<S> {
+    pub fn new(simulation: S) -> Self {
+        Debugger {
+            simulation,
+            step: 0,
+            finished: false,
+            breakpoints: vec![],
+        }
+    }
+
+    /// the simulation's values, including the step count as `step`.
+    fn values(&self) -> Vec<(String, i64)> {
+        let mut values = vec![("step".to_string(), self.step)];
+        values.extend(self.simulation.values());
+        values
+    }
+
+    fn summary(&self) -> String {
+        self.values()
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// runs steps until `stop` is reached, a breakpoint holds or the simulation ends.
+    fn run(&mut self, stop: Stop) -> Result<String, String> {
+        let mut steps = 0;
+        loop {
+            if let Stop::Steps(count) = stop {
+                if steps == count {
+                    return Ok(self.summary());
+                }
+            }
+            if self.finished || !self.simulation.step() {
+                self.finished = true;
+                return Ok(format!("finished: {}", self.summary()));
+            }
+            self.step += 1;
+            steps += 1;
+
+            let values = self.values();
+            if let Stop::Condition(condition) = &stop {
+                if condition.eval(&values)? {
+                    return Ok(format!("{} holds: {}", condition.source, self.summary()));
+                }
+            }
+            for (i, breakpoint) in self.breakpoints.iter().enumerate() {
+                if breakpoint.eval(&values)? {
+                    return Ok(format!(
+                        "breakpoint {} ({}) hit: {}",
+                        i + 1,
+                        breakpoint.source,
+                        self.summary()
+                    ));
+                }
+            }
+        }
+    }
+
+    /// runs a single command. returns `None` on `quit`.
+    pub fn command(&mut self, line: &str) -> Option<Result<String, String>> {
+        let line = line.trim();
+        let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+        let argument = argument.trim();
+
+        let result = match command {
+            "" | "s" | "step" => self.run(Stop::Steps(1)),
+            "n" | "next" => match argument.parse() {
+                Ok(count) if count > 0 => self.run(Stop::Steps(count)),
+                _ => Err(format!(
+                    "\"{}\" is not a positive number of steps",
+                    argument
+                )),
+            },
+            "c" | "continue" => self.run(Stop::End),
+            "u" | "until" => Condition::parse(argument)
+                .and_then(|condition| condition.eval(&self.values()).map(|_| condition))
+                .and_then(|condition| self.run(Stop::Condition(condition))),
+            "b" | "break" => Condition::parse(argument)
+                .and_then(|condition| condition.eval(&self.values()).map(|_| condition))
+                .map(|condition| {
+                    self.breakpoints.push(condition);
+                    format!("breakpoint {} set", self.breakpoints.len())
+                }),
+            "d" | "delete" => {
+                self.breakpoints.clear();
+                Ok("breakpoints removed".into())
+            }
+            "p" | "print" => Ok(format!(
+                "{}\n{}",
+                self.simulation.render().trim_end(),
+                self.summary()
+            )),
+            "h" | "help" => Ok(HELP.trim_end().into()),
+            "q" | "quit" => return None,
+            other => Err(format!("unknown command \"{}\", try `help`", other)),
+        };
+        Some(result)
+    }
+
+    /// reads commands from `input` until `quit` or the end of the input.
+    pub fn repl(&mut self, input: &mut impl BufRead, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", self.summary())?;
+        loop {
+            write!(out, "(debug) ")?;
+            out.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            match self.command(&line) {
+                Some(Ok(message)) => writeln!(out, "{}", message)?,
+                Some(Err(e)) => writeln!(out, "error: {}", e)?,
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// steps through `simulation` with commands read from the terminal.
+pub fn debug(simulation: impl Simulation) {
+    println!("Type `help` for the commands of the debugger.");
+    Debugger::new(simulation)
+        .repl(&mut io::stdin().lock(), &mut io::stdout())
+        .expect("could not read debugger commands");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// counts up to `limit`. `double` is twice the count.
+    struct Counter {
+        count: i64,
+        limit: i64,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.count == self.limit {
+                return false;
+            }
+            self.count += 1;
+            true
+        }
+
+        fn values(&self) -> Vec<(String, i64)> {
+            vec![
+                ("count".into(), self.count),
+                ("double".into(), self.count * 2),
+            ]
+        }
+
+        fn render(&self) -> String {
+            "#".repeat(self.count as usize)
+        }
+    }
+
+    fn debugger(limit: i64) -> Debugger<Counter> {
+        Debugger::new(Counter { count: 0, limit })
+    }
+
+    fn run(debugger: &mut Debugger<Counter>, line: &str) -> Result<String, String> {
+        debugger.command(line).unwrap()
+    }
+
+    #[test]
+    fn test_condition() {
+        let values = vec![("step".to_string(), 42), ("x".to_string(), -3)];
+        let holds = |source: &str| Condition::parse(source).unwrap().eval(&values);
+
+        assert_eq!(holds("42"), Ok(true));
+        assert_eq!(holds("step % 40 == 2"), Ok(true));
+        assert_eq!(holds("x < 0 && step >= 42"), Ok(true));
+        assert_eq!(holds("x <= -4"), Ok(false));
+        assert_eq!(holds("x != -3"), Ok(false));
+        assert!(holds("y > 1").is_err());
+
+        assert!(Condition::parse("x").is_err());
+        assert!(Condition::parse("x > y").is_err());
+        assert!(Condition::parse("x % 0 == 1").is_err());
+    }
+
+    #[test]
+    fn test_stepping() {
+        let mut debugger = debugger(10);
+        assert_eq!(run(&mut debugger, ""), Ok("step=1 count=1 double=2".into()));
+        assert_eq!(
+            run(&mut debugger, "n 3"),
+            Ok("step=4 count=4 double=8".into())
+        );
+        assert_eq!(
+            run(&mut debugger, "until double >= 12"),
+            Ok("double >= 12 holds: step=6 count=6 double=12".into())
+        );
+        assert_eq!(
+            run(&mut debugger, "print"),
+            Ok("######\nstep=6 count=6 double=12".into())
+        );
+        assert_eq!(
+            run(&mut debugger, "continue"),
+            Ok("finished: step=10 count=10 double=20".into())
+        );
+        assert_eq!(
+            run(&mut debugger, "step"),
+            Ok("finished: step=10 count=10 double=20".into())
+        );
+        assert!(run(&mut debugger, "n x").is_err());
+        assert!(run(&mut debugger, "jump").is_err());
+        assert!(debugger.command("q").is_none());
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = debugger(100);
+        assert_eq!(
+            run(&mut debugger, "break step % 40 == 20"),
+            Ok("breakpoint 1 set".into())
+        );
+        assert!(run(&mut debugger, "break missing == 1").is_err());
+        assert_eq!(
+            run(&mut debugger, "c"),
+            Ok("breakpoint 1 (step % 40 == 20) hit: step=20 count=20 double=40".into())
+        );
+        assert_eq!(
+            run(&mut debugger, "n 50"),
+            Ok("breakpoint 1 (step % 40 == 20) hit: step=60 count=60 double=120".into())
+        );
+        run(&mut debugger, "delete").unwrap();
+        assert_eq!(
+            run(&mut debugger, "c"),
+            Ok("finished: step=100 count=100 double=200".into())
+        );
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut input = "s\nb 3\nc\nquit\ns\n".as_bytes();
+        let mut out = vec![];
+        debugger(5).repl(&mut input, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "step=0 count=0 double=0\n(debug) step=1 count=1 double=2\n(debug) breakpoint 1 set\n(debug) breakpoint 1 (3) hit: step=3 count=3 double=6\n(debug) "
+        );
+    }
+}
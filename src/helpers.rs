@@ -18,6 +18,23 @@ pub struct Grid<T> {
 }
 
 impl<T> Grid<T> {
+    /// builds a grid from puzzle input, one row per line, mapping every char with `parse_cell`
+    pub fn from_input(input: &str, parse_cell: impl Fn(char) -> T) -> Self {
+        let mut map = HashMap::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                map.insert((x, y), parse_cell(ch));
+                width = width.max(x + 1);
+            }
+            height = y + 1;
+        }
+
+        Grid { map, width, height }
+    }
+
     /// returns iterator over adjacent coords
     pub fn get_adjacent_coords(
         &self,
@@ -67,38 +84,14 @@ impl<T> Grid<T> {
     }
 
     pub fn get_min_coord(&self) -> Coord {
-        let min_x = self
-            .map
-            .keys()
-            .into_iter()
-            .min_by(|a, b| a.0.cmp(&b.0))
-            .unwrap()
-            .0;
-        let min_y = self
-            .map
-            .keys()
-            .into_iter()
-            .min_by(|a, b| a.1.cmp(&b.1))
-            .unwrap()
-            .1;
+        let min_x = self.map.keys().min_by(|a, b| a.0.cmp(&b.0)).unwrap().0;
+        let min_y = self.map.keys().min_by(|a, b| a.1.cmp(&b.1)).unwrap().1;
         (min_x, min_y)
     }
 
     pub fn get_max_coord(&self) -> Coord {
-        let max_x = self
-            .map
-            .keys()
-            .into_iter()
-            .max_by(|a, b| a.0.cmp(&b.0))
-            .unwrap()
-            .0;
-        let max_y = self
-            .map
-            .keys()
-            .into_iter()
-            .max_by(|a, b| a.1.cmp(&b.1))
-            .unwrap()
-            .1;
+        let max_x = self.map.keys().max_by(|a, b| a.0.cmp(&b.0)).unwrap().0;
+        let max_y = self.map.keys().max_by(|a, b| a.1.cmp(&b.1)).unwrap().1;
         (max_x, max_y)
     }
 }
@@ -114,7 +107,7 @@ impl<T: Display> fmt::Display for Grid<T> {
                 let val = self.map.get(&(x, y)).unwrap();
                 result.push_str(format!("{}", val).as_str());
             }
-            result.push_str("\n");
+            result.push('\n');
         }
         write!(f, "{}", result)
     }
@@ -3,10 +3,23 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use std::collections::HashMap;
 use std::env;
 use std::fs;
+use std::sync::OnceLock;
 
+pub mod checksums;
+pub mod crypto;
+pub mod debugger;
 pub mod helpers;
+pub mod puzzles;
+pub mod submissions;
+
+/// year of the event solved in this repository, used when no `--year` is passed.
+pub const YEAR: i16 = 2022;
+
+/// days that have a solution in `src/bin/`. `cargo scaffold` keeps this list up to date.
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -36,45 +49,259 @@ macro_rules! solve {
             }
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        if advent_of_code::debugger::requested_part().is_some() {
+            advent_of_code::debugger::no_simulation();
+        }
+        if advent_of_code::part_selected($part) {
+            println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
+            print_result($solver, $input);
+        }
     }};
 }
 
+/// environment variable that replaces the puzzle input of a solution, used by `cargo serve`.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// environment variable limiting `solve!` to one part, used by `cargo serve`.
+pub const PART_ENV: &str = "AOC_PART";
+
+/// whether `solve!` runs `part`: always, unless another part is selected with `AOC_PART`.
+pub fn part_selected(part: u8) -> bool {
+    match env::var(PART_ENV) {
+        Ok(selected) => selected.trim() == part.to_string(),
+        Err(_) => true,
+    }
+}
+
+/// environment variable selecting the level of `debug_log!` and `trace!` output: `debug` or `trace`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    #[default]
+    Off,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn parse(val: &str) -> Self {
+        match val.trim().to_lowercase().as_str() {
+            "debug" | "1" => LogLevel::Debug,
+            "trace" | "2" => LogLevel::Trace,
+            _ => LogLevel::Off,
+        }
+    }
+
+    /// whether output of `level` is printed when this is the log level.
+    pub fn enables(self, level: LogLevel) -> bool {
+        level != LogLevel::Off && level <= self
+    }
+}
+
+/// the log level set in `AOC_LOG`, read once per run.
+pub fn log_enabled(level: LogLevel) -> bool {
+    static LEVEL: OnceLock<LogLevel> = OnceLock::new();
+    let current = *LEVEL.get_or_init(|| {
+        env::var(LOG_ENV)
+            .map(|val| LogLevel::parse(&val))
+            .unwrap_or_default()
+    });
+    current.enables(level)
+}
+
+/// prints to stderr if `AOC_LOG` is `debug` or `trace`. in release builds, the check is `false`
+/// at compile time, so the call and its arguments are optimized away and don't affect timings.
+#[macro_export]
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) && $crate::log_enabled($crate::LogLevel::Debug) {
+            eprintln!("[debug] {}", format_args!($($arg)*));
+        }
+    };
+}
+
+/// like `debug_log!`, for verbose output such as grids. printed if `AOC_LOG` is `trace`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) && $crate::log_enabled($crate::LogLevel::Trace) {
+            eprintln!("[trace] {}", format_args!($($arg)*));
+        }
+    };
+}
+
 pub fn read_file(folder: &str, day: u8) -> String {
+    if folder == "inputs" {
+        if let Some(path) = env::var_os(INPUT_ENV) {
+            return fs::read_to_string(path).expect("could not open input file");
+        }
+    }
+
     let cwd = env::current_dir().unwrap();
 
     let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    let encrypted_path = crypto::encrypted_path(&filepath);
+
+    let contents = if filepath.exists() || !encrypted_path.exists() {
+        let f = fs::read_to_string(filepath);
+        f.expect("could not open input file")
+    } else {
+        // fall back to the committed ciphertext of the input.
+        let name = format!("{:02}.txt", day);
+        let contents =
+            fs::read_to_string(encrypted_path).expect("could not open encrypted input file");
+        crypto::read_key()
+            .and_then(|key| crypto::decrypt(&key, &name, &contents))
+            .unwrap_or_else(|e| panic!("input \"{}\" is encrypted: {}", name, e))
+    };
+
+    if folder == "inputs" {
+        if let Some(warning) = checksums::verify(&checksums::read_checksums(), day, &contents) {
+            eprintln!("{}warning{}: {}", ANSI_BOLD, ANSI_RESET, warning);
+        }
+    }
+    contents
+}
+
+/// environment variable naming the directory visualizations are saved to, set by `cargo all --html`.
+pub const VISUALIZE_ENV: &str = "AOC_VISUALIZE";
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// saves a text picture of a solution (e.g. a grid) to embed it in the html report.
+/// `draw` is only called if the solution is run by `cargo all --html`.
+pub fn visualize(day: u8, name: &str, draw: impl FnOnce() -> String) {
+    let dir = match env::var_os(VISUALIZE_ENV) {
+        Some(dir) => std::path::PathBuf::from(dir),
+        None => return,
+    };
+    let name: String = name
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '-' })
+        .collect();
+    let path = dir.join(format!("{:02}-{}.txt", day, name));
+    if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&path, draw())) {
+        eprintln!("could not save visualization {}: {}", path.display(), e);
+    }
+}
+
+/// Expected answers for the example inputs, keyed by day. `None` marks an answer that is not known yet.
+pub type ExpectedAnswers = HashMap<u8, [Option<String>; 2]>;
+
+pub fn parse_expected_answers(contents: &str) -> ExpectedAnswers {
+    contents
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let mut parts = l.split_whitespace();
+            let day = parts.next()?.parse().ok()?;
+            let mut answer = || {
+                parts
+                    .next()
+                    .filter(|val| *val != "-")
+                    .map(|val| val.to_string())
+            };
+            Some((day, [answer(), answer()]))
+        })
+        .collect()
+}
+
+/// fills in unknown answers for `day` in the contents of an answers file, adding a line for the
+/// day if it is not listed. answers that are already known are kept.
+pub fn merge_expected_answers(contents: &str, day: u8, answers: &[Option<String>; 2]) -> String {
+    let known = parse_expected_answers(contents)
+        .remove(&day)
+        .unwrap_or_default();
+    let format_answer = |i: usize| {
+        known[i]
+            .as_deref()
+            .or(answers[i].as_deref())
+            .unwrap_or("-")
+            .to_string()
+    };
+    let entry = format!("{:02} {} {}", day, format_answer(0), format_answer(1));
+
+    let line_day = |l: &str| {
+        let l = l.trim();
+        match l.starts_with('#') {
+            true => None,
+            false => l.split_whitespace().next()?.parse::<u8>().ok(),
+        }
+    };
+
+    let mut lines: Vec<&str> = contents.lines().collect();
+    match lines.iter().position(|l| line_day(l) == Some(day)) {
+        Some(i) => lines[i] = &entry,
+        None => {
+            let insert_at = lines
+                .iter()
+                .position(|l| line_day(l).is_some_and(|d| d > day))
+                .unwrap_or(lines.len());
+            lines.insert(insert_at, &entry);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+pub fn read_expected_answers() -> ExpectedAnswers {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd.join("src").join("examples").join("answers.txt");
+
+    match fs::read_to_string(filepath) {
+        Ok(contents) => parse_expected_answers(&contents),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// finds the line `solve!` printed the result of `part` on. `None` if the part is not solved.
+fn result_line(output: &str, part: u8) -> Option<&str> {
+    let header = format!("Part {}{}", part, ANSI_RESET);
+    output
+        .lines()
+        .skip_while(|l| !l.contains(&header))
+        .skip(1)
+        .find(|l| l.contains("(elapsed: ") || l.starts_with("not solved."))
+        .filter(|l| !l.starts_with("not solved."))
+}
+
+/// extracts the answer printed by `solve!` for `part` from a solution's output.
+pub fn parse_answer(output: &str, part: u8) -> Option<String> {
+    result_line(output, part)
+        .and_then(|l| l.split("(elapsed: ").next())
+        .map(|l| l.trim_end_matches(ANSI_ITALIC).trim().to_string())
+}
+
+/// extracts the execution time of `part` in milliseconds from a solution's output.
+pub fn parse_part_time(output: &str, part: u8) -> Option<f64> {
+    result_line(output, part).map(parse_line_time)
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
     val.split(postfix).next().unwrap().parse().unwrap()
 }
 
+fn parse_line_time(l: &str) -> f64 {
+    if !l.contains("elapsed:") {
+        return 0_f64;
+    }
+    let timing = l.split("(elapsed: ").last().unwrap();
+    // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    if timing.contains("ns)") {
+        0_f64 // range below rounding precision.
+    } else if timing.contains("µs)") {
+        parse_time(timing, "µs") / 1000_f64
+    } else if timing.contains("ms)") {
+        parse_time(timing, "ms")
+    } else if timing.contains("s)") {
+        parse_time(timing, "s") * 1000_f64
+    } else {
+        0_f64
+    }
+}
+
 pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+    output.lines().map(parse_line_time).sum()
 }
 
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -95,6 +322,83 @@ macro_rules! assert_approx_eq {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_log_level() {
+        assert_eq!(LogLevel::parse("debug"), LogLevel::Debug);
+        assert_eq!(LogLevel::parse("TRACE\n"), LogLevel::Trace);
+        assert_eq!(LogLevel::parse("2"), LogLevel::Trace);
+        assert_eq!(LogLevel::parse(""), LogLevel::Off);
+
+        assert!(!LogLevel::default().enables(LogLevel::Debug));
+        assert!(!LogLevel::default().enables(LogLevel::Trace));
+        assert!(LogLevel::Debug.enables(LogLevel::Debug));
+        assert!(!LogLevel::Debug.enables(LogLevel::Trace));
+        assert!(LogLevel::Trace.enables(LogLevel::Debug));
+        assert!(LogLevel::Trace.enables(LogLevel::Trace));
+    }
+
+    #[test]
+    fn test_parse_expected_answers() {
+        let answers =
+            parse_expected_answers("# day part_one part_two\n05 CMZ MCD\n12 31 -\n\n15\n");
+        assert_eq!(
+            answers.get(&5),
+            Some(&[Some("CMZ".to_string()), Some("MCD".to_string())])
+        );
+        assert_eq!(answers.get(&12), Some(&[Some("31".to_string()), None]));
+        assert_eq!(answers.get(&15), Some(&[None, None]));
+        assert_eq!(answers.get(&1), None);
+    }
+
+    #[test]
+    fn test_merge_expected_answers() {
+        let contents = "# comment\n01 1 2\n03 5 -\n";
+        assert_eq!(
+            merge_expected_answers(contents, 2, &[None, None]),
+            "# comment\n01 1 2\n02 - -\n03 5 -\n"
+        );
+        assert_eq!(
+            merge_expected_answers(contents, 3, &[Some("6".into()), Some("7".into())]),
+            "# comment\n01 1 2\n03 5 7\n"
+        );
+        assert_eq!(
+            merge_expected_answers("", 4, &[Some("CMZ".into()), None]),
+            "04 CMZ -\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        let output = format!(
+            "🎄 {b}Part 1{r} 🎄\n13140 {i}(elapsed: 8.21µs){r}\n🎄 {b}Part 2{r} 🎄\n##..\n..##\n0 {i}(elapsed: 1.05ms){r}\n",
+            b = ANSI_BOLD,
+            i = ANSI_ITALIC,
+            r = ANSI_RESET
+        );
+        assert_eq!(parse_answer(&output, 1), Some("13140".to_string()));
+        assert_eq!(parse_answer(&output, 2), Some("0".to_string()));
+
+        let output = format!(
+            "🎄 {b}Part 1{r} 🎄\nnot solved.\n",
+            b = ANSI_BOLD,
+            r = ANSI_RESET
+        );
+        assert_eq!(parse_answer(&output, 1), None);
+        assert_eq!(parse_answer(&output, 2), None);
+    }
+
+    #[test]
+    fn test_parse_part_time() {
+        let output = format!(
+            "🎄 {b}Part 1{r} 🎄\n13140 {i}(elapsed: 8.21µs){r}\n🎄 {b}Part 2{r} 🎄\nnot solved.\n",
+            b = ANSI_BOLD,
+            i = ANSI_ITALIC,
+            r = ANSI_RESET
+        );
+        assert_approx_eq!(parse_part_time(&output, 1).unwrap(), 0.00821_f64);
+        assert_eq!(parse_part_time(&output, 2), None);
+    }
+
     #[test]
     fn test_parse_exec_time() {
         assert_approx_eq!(
@@ -0,0 +1,268 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub fn puzzle_path(day: u8) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+/// reads the puzzle description saved by `cargo download <day> --puzzle`.
+pub fn read_puzzle(day: u8) -> io::Result<String> {
+    fs::read_to_string(puzzle_path(day))
+}
+
+/// renders inline markdown: `*emphasis*` in bold, `` `code` `` in italics.
+fn render_inline(line: &str) -> String {
+    let mut result = String::new();
+    let mut emphasis = false;
+    let mut code = false;
+
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' if !code => result.extend(chars.next()),
+            '*' if !code => {
+                emphasis = !emphasis;
+                result.push_str(if emphasis { ANSI_BOLD } else { ANSI_RESET });
+            }
+            '`' => {
+                code = !code;
+                result.push_str(if code { ANSI_ITALIC } else { ANSI_RESET });
+                if !code && emphasis {
+                    result.push_str(ANSI_BOLD);
+                }
+            }
+            _ => result.push(ch),
+        }
+    }
+
+    if emphasis || code {
+        result.push_str(ANSI_RESET);
+    }
+    result
+}
+
+/// renders the markdown of a puzzle description for the terminal.
+pub fn render_markdown(markdown: &str) -> String {
+    let mut result = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            result.push_str("    ");
+            result.push_str(line);
+        } else if line.starts_with('#') {
+            let heading = line.trim_start_matches('#').trim();
+            result.push_str(&format!("{}{}{}", ANSI_BOLD, heading, ANSI_RESET));
+        } else if line.chars().all(|ch| ch == '-' || ch == '=') && !line.is_empty() {
+            // setext heading underline.
+            result.push_str(line);
+        } else {
+            result.push_str(&render_inline(line));
+        }
+        result.push('\n');
+    }
+    result
+}
+
+fn decode_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// returns all code blocks of a puzzle description, each with the paragraph preceding it.
+fn code_blocks(markdown: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    let mut paragraph = String::new();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        match block.as_mut() {
+            Some(code) if trimmed.starts_with("```") || trimmed.starts_with("</code></pre>") => {
+                blocks.push((paragraph.clone(), decode_html(code)));
+                block = None;
+            }
+            Some(code) if trimmed.ends_with("</code></pre>") => {
+                code.push_str(line.trim_end().trim_end_matches("</code></pre>"));
+                code.push('\n');
+                blocks.push((paragraph.clone(), decode_html(code)));
+                block = None;
+            }
+            Some(code) => {
+                code.push_str(line);
+                code.push('\n');
+            }
+            None if trimmed.starts_with("```") => block = Some(String::new()),
+            None if trimmed.starts_with("<pre><code>") => {
+                let rest = trimmed.trim_start_matches("<pre><code>");
+                match rest.strip_suffix("</code></pre>") {
+                    Some(code) => blocks.push((paragraph.clone(), decode_html(code) + "\n")),
+                    None if rest.is_empty() => block = Some(String::new()),
+                    None => block = Some(format!("{}\n", rest)),
+                }
+            }
+            None if trimmed.is_empty() => {}
+            None => paragraph = trimmed.to_string(),
+        }
+    }
+    blocks
+}
+
+/// finds the example inputs of a puzzle description: code blocks introduced by a paragraph
+/// mentioning an example. falls back to the first code block.
+pub fn extract_examples(markdown: &str) -> Vec<String> {
+    let blocks = code_blocks(markdown);
+    let examples: Vec<String> = blocks
+        .iter()
+        .filter(|(paragraph, _)| paragraph.to_lowercase().contains("example"))
+        .map(|(_, code)| code.clone())
+        .collect();
+
+    match examples.is_empty() {
+        true => blocks.into_iter().map(|(_, code)| code).take(1).collect(),
+        false => examples,
+    }
+}
+
+/// returns the emphasized code values (`` *`24000`* `` or `` `*24000*` ``) of a line.
+fn emphasized_values(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    for (open, close) in [("*`", "`*"), ("`*", "*`")] {
+        let mut rest = line;
+        while let Some(start) = rest.find(open) {
+            let after = &rest[start + open.len()..];
+            match after.find(close) {
+                Some(end) => {
+                    values.push(after[..end].to_string());
+                    rest = &after[end + close.len()..];
+                }
+                None => break,
+            }
+        }
+    }
+    values
+}
+
+/// proposes the expected example answers of both parts: the last emphasized value of the
+/// description of each part.
+pub fn extract_answers(markdown: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+    let mut part = 0;
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if line.contains("--- Part Two ---") {
+            part = 1;
+        } else if !in_code_block {
+            if let Some(value) = emphasized_values(line).pop() {
+                answers[part] = Some(value);
+            }
+        }
+    }
+    answers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = "\\--- Day 1: Calorie Counting ---\n----------\n\n## Part One\n\nThe answer is *`24000`* calories.\n\n```\n1000\n*2000*\n```\n";
+        let rendered = render_markdown(markdown);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines[0], "--- Day 1: Calorie Counting ---");
+        assert_eq!(lines[3], format!("{}Part One{}", ANSI_BOLD, ANSI_RESET));
+        assert_eq!(
+            lines[5],
+            format!(
+                "The answer is {b}{i}24000{r}{b}{r} calories.",
+                b = ANSI_BOLD,
+                i = ANSI_ITALIC,
+                r = ANSI_RESET
+            )
+        );
+        assert_eq!(lines[7], "    1000");
+        assert_eq!(lines[8], "    *2000*");
+    }
+
+    const PUZZLE: &str = "\\--- Day 1: Calorie Counting ---
+----------
+
+The Elves take turns writing down the number of *Calories*.
+
+For example, suppose the Elves finish writing their items' Calories and end up with the following list:
+
+```
+1000
+2000
+
+3000
+```
+
+This list represents the Calories of the food carried by five Elves:
+
+```
+Elf 1: 3000
+```
+
+In the example above, this is *`24000`* (carried by the fourth Elf).
+
+Find the Elf carrying the most Calories. *How many total Calories is that Elf carrying?*
+
+\\--- Part Two ---
+----------
+
+In the example above, the sum of the Calories carried by these three elves is `*45000*`.
+
+Another example:
+
+<pre><code>a &lt; b
+c
+</code></pre>
+";
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(
+            extract_examples(PUZZLE),
+            vec!["1000\n2000\n\n3000\n".to_string(), "a < b\nc\n".to_string()]
+        );
+        assert_eq!(
+            extract_examples("text\n\n```\nfirst\n```\n\n```\nsecond\n```\n"),
+            vec!["first\n".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_extract_answers() {
+        assert_eq!(
+            extract_answers(PUZZLE),
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+        assert_eq!(extract_answers("no answers here"), [None, None]);
+    }
+}
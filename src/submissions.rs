@@ -0,0 +1,245 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parse_display::{Display, FromStr};
+
+/// Result of submitting an answer, as reported by the Advent of Code website.
+#[derive(Display, FromStr, Debug, Clone, Copy, PartialEq, Eq)]
+#[display(style = "snake_case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl Outcome {
+    /// classifies the text the website (or aoc-cli) responded with.
+    pub fn from_response(response: &str) -> Self {
+        let response = response.to_lowercase();
+        if response.contains("that's the right answer") {
+            Outcome::Right
+        } else if response.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if response.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if response.contains("that's not the right answer") {
+            Outcome::Wrong
+        } else if response.contains("you gave an answer too recently") {
+            Outcome::RateLimited
+        } else if response.contains("did you already complete it") {
+            Outcome::AlreadyCompleted
+        } else {
+            Outcome::Unknown
+        }
+    }
+}
+
+/// A single line of the submission log.
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+#[display("{timestamp} {year} {day} {part} {outcome} {answer}")]
+pub struct Submission {
+    pub timestamp: u64,
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+impl Submission {
+    pub fn new(year: i16, day: u8, part: u8, outcome: Outcome, answer: &str) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Submission {
+            timestamp,
+            year,
+            day,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        }
+    }
+}
+
+/// checks `answer` against earlier rejected submissions for the same part.
+/// returns the reason if the answer is already known to be wrong.
+pub fn find_known_wrong(
+    submissions: &[Submission],
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Option<String> {
+    let value = answer.parse::<i64>().ok();
+
+    submissions
+        .iter()
+        .filter(|s| s.year == year && s.day == day && s.part == part)
+        .find_map(|s| {
+            let bound = s.answer.parse::<i64>().ok();
+            match (s.outcome, value, bound) {
+                (Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow, _, _)
+                    if s.answer == answer =>
+                {
+                    Some(format!("{} was already rejected ({})", answer, s.outcome))
+                }
+                (Outcome::TooHigh, Some(value), Some(bound)) if value > bound => Some(format!(
+                    "{} is higher than {}, which was too high",
+                    answer, bound
+                )),
+                (Outcome::TooLow, Some(value), Some(bound)) if value < bound => Some(format!(
+                    "{} is lower than {}, which was too low",
+                    answer, bound
+                )),
+                _ => None,
+            }
+        })
+}
+
+/// a part earns a star once an answer for it was accepted. `already_completed` does not count:
+/// the website answers the same for a part that is still locked.
+pub fn has_star(submissions: &[Submission], year: i16, day: u8, part: u8) -> bool {
+    submissions
+        .iter()
+        .any(|s| s.year == year && s.day == day && s.part == part && s.outcome == Outcome::Right)
+}
+
+fn log_path() -> std::path::PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("src")
+        .join("submissions.txt")
+}
+
+pub fn parse_submissions(contents: &str) -> Vec<Submission> {
+    contents
+        .lines()
+        .filter_map(|l| l.trim().parse().ok())
+        .collect()
+}
+
+/// reads all recorded submissions from `src/submissions.txt`, oldest first.
+pub fn read_submissions() -> Vec<Submission> {
+    match fs::read_to_string(log_path()) {
+        Ok(contents) => parse_submissions(&contents),
+        Err(_) => vec![],
+    }
+}
+
+/// appends a submission to `src/submissions.txt`.
+pub fn record_submission(submission: &Submission) -> std::io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path())?;
+    writeln!(file, "{}", submission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_response() {
+        assert_eq!(
+            Outcome::from_response("That's the right answer! You are one gold star closer."),
+            Outcome::Right
+        );
+        assert_eq!(
+            Outcome::from_response(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            ),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::from_response("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::from_response("That's not the right answer. If you're stuck, ..."),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::from_response("You gave an answer too recently; you have to wait."),
+            Outcome::RateLimited
+        );
+        assert_eq!(
+            Outcome::from_response(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Outcome::AlreadyCompleted
+        );
+        assert_eq!(Outcome::from_response("500"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_find_known_wrong() {
+        let submissions = parse_submissions(
+            "1 2022 9 1 too_high 6000\n2 2022 9 1 too_low 5000\n3 2022 9 1 wrong 5500\n4 2022 9 2 right 2000\n5 2022 9 2 wrong 0\n",
+        );
+        assert!(find_known_wrong(&submissions, 2022, 9, 1, "6000").is_some());
+        assert!(find_known_wrong(&submissions, 2022, 9, 1, "6001").is_some());
+        assert!(find_known_wrong(&submissions, 2022, 9, 1, "4999").is_some());
+        assert!(find_known_wrong(&submissions, 2022, 9, 1, "5500").is_some());
+        assert!(find_known_wrong(&submissions, 2022, 9, 2, "0").is_some());
+        assert_eq!(find_known_wrong(&submissions, 2022, 9, 1, "5800"), None);
+        assert_eq!(find_known_wrong(&submissions, 2022, 9, 2, "6000"), None);
+        assert_eq!(find_known_wrong(&submissions, 2022, 10, 1, "6000"), None);
+        assert_eq!(find_known_wrong(&submissions, 2021, 9, 1, "6000"), None);
+    }
+
+    #[test]
+    fn test_has_star() {
+        let submissions = parse_submissions(
+            "1 2022 12 1 too_low 30\n2 2022 12 1 right 31\n3 2022 13 2 already_completed 140\n",
+        );
+        assert!(has_star(&submissions, 2022, 12, 1));
+        assert!(!has_star(&submissions, 2021, 12, 1));
+        assert!(!has_star(&submissions, 2022, 12, 2));
+        assert!(!has_star(&submissions, 2022, 13, 2));
+        assert!(!has_star(&submissions, 2022, 14, 1));
+    }
+
+    #[test]
+    fn test_parse_submissions() {
+        let submissions =
+            parse_submissions("1670000000 2022 5 1 right CMZ\n1670000100 2021 10 2 too_low 1 2\n");
+        assert_eq!(
+            submissions,
+            vec![
+                Submission {
+                    timestamp: 1670000000,
+                    year: 2022,
+                    day: 5,
+                    part: 1,
+                    outcome: Outcome::Right,
+                    answer: "CMZ".to_string()
+                },
+                Submission {
+                    timestamp: 1670000100,
+                    year: 2021,
+                    day: 10,
+                    part: 2,
+                    outcome: Outcome::TooLow,
+                    answer: "1 2".to_string()
+                }
+            ]
+        );
+        assert_eq!(
+            submissions[1].to_string(),
+            "1670000100 2021 10 2 too_low 1 2"
+        );
+    }
+}